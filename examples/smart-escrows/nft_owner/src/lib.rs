@@ -4,13 +4,16 @@
 extern crate std;
 
 use xrpl_wasm_stdlib::core::ledger_objects::current_escrow;
+use xrpl_wasm_stdlib::core::ledger_objects::nft::is_nft_owned_by;
 use xrpl_wasm_stdlib::core::ledger_objects::traits::CurrentEscrowFields;
 use xrpl_wasm_stdlib::core::locator::Locator;
 use xrpl_wasm_stdlib::core::types::nft::{NFT_ID_SIZE, NFToken};
 use xrpl_wasm_stdlib::host::Error::InternalError;
 use xrpl_wasm_stdlib::host::get_tx_nested_field;
 use xrpl_wasm_stdlib::host::trace::{DataRepr, trace_data, trace_num};
-use xrpl_wasm_stdlib::host::{Error, Result, Result::Err, Result::Ok};
+use xrpl_wasm_stdlib::host::{
+    Error, EscrowDecision, Result, Result::Err, Result::Ok, finish_traced,
+};
 use xrpl_wasm_stdlib::sfield;
 use xrpl_wasm_stdlib::types::{ContractData, XRPL_CONTRACT_DATA_SIZE};
 
@@ -97,17 +100,12 @@ pub extern "C" fn finish() -> i32 {
         }
     };
 
-    // Check if destination owns the NFT by attempting to retrieve its URI
-    match nft_token.uri(&destination) {
-        Ok(_uri) => {
-            let _ = trace_data("NFT is owned by destination", &[], DataRepr::AsHex);
-            1 // <-- Finish the escrow successfully
-        }
+    // Check if destination owns the NFT
+    match is_nft_owned_by(&destination, &nft_token) {
+        Ok(true) => finish_traced(EscrowDecision::Release, "NFT is owned by destination"),
+        Ok(false) => finish_traced(EscrowDecision::Keep, "NFT is NOT owned by destination"),
         Err(e) => {
-            let _ = trace_num(
-                "NFT is NOT owned by destination. Error code:",
-                e.code() as i64,
-            );
+            let _ = trace_num("Error checking NFT ownership:", e.code() as i64);
             0 // <-- Do not execute the escrow
         }
     }
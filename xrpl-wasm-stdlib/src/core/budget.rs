@@ -0,0 +1,111 @@
+//! # Computation Budget
+//!
+//! Every `finish` call runs under a gas limit set by the host. When the limit is reached the
+//! host aborts the contract outright, so an escrow that walks a collection of unknown size (for
+//! example, every NFToken an account owns) can fail on one account and succeed on another.
+//!
+//! The host does not currently report how much gas remains (see [`remaining_gas`]), so the
+//! practical defence is to bound loops explicitly with a [`StepBudget`] and treat running out of
+//! steps as a deliberate, deterministic outcome.
+//!
+//! ## Example
+//!
+//! ```
+//! use xrpl_wasm_stdlib::core::budget::StepBudget;
+//!
+//! let mut budget = StepBudget::new(3);
+//! let mut visited = 0;
+//! for _ in 0..10 {
+//!     if !budget.try_step() {
+//!         break;
+//!     }
+//!     visited += 1;
+//! }
+//! assert_eq!(visited, 3);
+//! assert!(budget.is_exhausted());
+//! ```
+
+use crate::host::{Error, Result};
+
+/// Returns the gas remaining for the current `finish` call.
+///
+/// The host does not currently expose the remaining computation budget to smart escrows, so this
+/// always returns `Error::NotImplemented`. Use a [`StepBudget`] to bound long-running loops
+/// instead.
+#[inline]
+pub fn remaining_gas() -> Result<u64> {
+    Result::Err(Error::NotImplemented)
+}
+
+/// A countdown of loop iterations, used to cap work done over collections of unknown size.
+///
+/// ## Derived Traits
+///
+/// - `Copy`: Efficient for this 8-byte struct, enabling implicit copying
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepBudget {
+    remaining: u32,
+    exhausted: bool,
+}
+
+impl StepBudget {
+    /// Creates a budget that allows `max_steps` steps.
+    #[inline]
+    pub const fn new(max_steps: u32) -> Self {
+        Self {
+            remaining: max_steps,
+            exhausted: false,
+        }
+    }
+
+    /// Consumes one step, returning `false` (and marking the budget exhausted) if none are left.
+    #[inline]
+    pub fn try_step(&mut self) -> bool {
+        if self.remaining == 0 {
+            self.exhausted = true;
+            return false;
+        }
+        self.remaining -= 1;
+        true
+    }
+
+    /// Returns the number of steps still available.
+    #[inline]
+    pub const fn remaining(&self) -> u32 {
+        self.remaining
+    }
+
+    /// Returns `true` once a step has been refused because the budget ran out.
+    ///
+    /// Using up the last step exactly does not exhaust the budget; only asking for one more
+    /// does. This distinguishes "the loop finished within budget" from "the loop was cut short".
+    #[inline]
+    pub const fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::NOT_IMPLEMENTED;
+
+    #[test]
+    fn test_remaining_gas_is_unsupported() {
+        assert_eq!(remaining_gas().err().unwrap().code(), NOT_IMPLEMENTED);
+    }
+
+    #[test]
+    fn test_step_budget_exhausts_only_when_exceeded() {
+        let mut budget = StepBudget::new(2);
+        assert!(budget.try_step());
+        assert!(budget.try_step());
+        assert_eq!(budget.remaining(), 0);
+        assert!(!budget.is_exhausted());
+
+        assert!(!budget.try_step());
+        assert!(budget.is_exhausted());
+    }
+}
@@ -15,7 +15,10 @@ pub const ACCOUNT_ONE: AccountID = {
 /// Indivisible unit of XRP
 pub const ONE_DROP: u64 = 1;
 
+/// Drops in one XRP
+pub const DROPS_PER_XRP: u64 = 1_000_000;
+
 /// 100 billion XRP
 pub const MAX_XRP: u64 = 100_000_000_000u64;
 /// Maximum possible drops of XRP
-pub const MAX_DROPS: u64 = MAX_XRP * 1_000_000;
+pub const MAX_DROPS: u64 = MAX_XRP * DROPS_PER_XRP;
@@ -2,6 +2,10 @@
 //!
 //! This module provides functionality for handling EscrowFinish transactions within the
 //! XRPL Programmability environment.
+//!
+//! These accessors read the triggering transaction only. Fields of the escrow being finished,
+//! such as the locked `Amount`, live on the escrow object; read them through
+//! [`get_current_escrow`](crate::core::ledger_objects::current_escrow::get_current_escrow).
 
 use crate::core::current_tx::traits::{EscrowFinishFields, TransactionCommonFields};
 
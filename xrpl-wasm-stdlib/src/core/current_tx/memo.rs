@@ -0,0 +1,152 @@
+//! # Memos
+//!
+//! Helpers for reading the `Memos` array of the current transaction. Each memo is an inner
+//! object with optional `MemoType`, `MemoData` and `MemoFormat` fields.
+//!
+//! A contract that takes instructions through memos usually looks for one memo by its type
+//! rather than relying on its position, which lets the submitter attach other memos freely.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::current_tx::memo::find_by_type;
+//! # fn example() -> i32 {
+//! let command = match find_by_type(b"command").unwrap_or_panic() {
+//!     Some(memo) => memo.memo_data,
+//!     None => return 0,
+//! };
+//! # command.map_or(0, |data| data.len() as i32)
+//! # }
+//! ```
+
+use crate::core::locator::Locator;
+use crate::core::types::blob::{Blob, MEMO_BLOB_SIZE};
+use crate::host::error_codes::FIELD_NOT_FOUND;
+use crate::host::{Error, Result, get_tx_array_len, get_tx_nested_field};
+use crate::sfield;
+
+/// A single entry of the current transaction's `Memos` array. Absent fields are `None`.
+///
+/// Note: `Copy` is intentionally not derived due to the struct's size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Memo {
+    /// Identifies the kind of memo, conventionally a URL or short name.
+    pub memo_type: Option<Blob<MEMO_BLOB_SIZE>>,
+    /// The content of the memo.
+    pub memo_data: Option<Blob<MEMO_BLOB_SIZE>>,
+    /// How the content is encoded, conventionally a MIME type.
+    pub memo_format: Option<Blob<MEMO_BLOB_SIZE>>,
+}
+
+/// Returns the number of memos on the current transaction (0 if it has none).
+pub fn memo_count() -> Result<i32> {
+    let len = unsafe { get_tx_array_len(sfield::Memos) };
+    match len {
+        len if len >= 0 => Result::Ok(len),
+        FIELD_NOT_FOUND => Result::Ok(0),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// Reads `Memos[index]` from the current transaction.
+pub fn get_memo(index: i32) -> Result<Memo> {
+    let mut locator = Locator::new();
+    locator.pack(sfield::Memos);
+    locator.pack(index);
+    locator.pack(sfield::MemoType);
+    let memo_type = match read_memo_field(&locator) {
+        Result::Ok(memo_type) => memo_type,
+        Result::Err(e) => return Result::Err(e),
+    };
+
+    locator.repack_last(sfield::MemoData);
+    let memo_data = match read_memo_field(&locator) {
+        Result::Ok(memo_data) => memo_data,
+        Result::Err(e) => return Result::Err(e),
+    };
+
+    locator.repack_last(sfield::MemoFormat);
+    match read_memo_field(&locator) {
+        Result::Ok(memo_format) => Result::Ok(Memo {
+            memo_type,
+            memo_data,
+            memo_format,
+        }),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Returns the first memo of the current transaction whose `MemoType` equals `memo_type`.
+///
+/// Memos are scanned in order and only the `MemoType` of each is read until one matches.
+///
+/// # Returns
+///
+/// * `Ok(Some(Memo))` - The first matching memo
+/// * `Ok(None)` - No memo has that type, or the transaction has no memos
+/// * `Err(Error)` - If the host fails to read a memo
+pub fn find_by_type(memo_type: &[u8]) -> Result<Option<Memo>> {
+    let count = match memo_count() {
+        Result::Ok(count) => count,
+        Result::Err(e) => return Result::Err(e),
+    };
+    for index in 0..count {
+        let mut locator = Locator::new();
+        locator.pack(sfield::Memos);
+        locator.pack(index);
+        locator.pack(sfield::MemoType);
+        match read_memo_field(&locator) {
+            Result::Ok(Some(found)) if found.as_slice() == memo_type => {
+                return match get_memo(index) {
+                    Result::Ok(memo) => Result::Ok(Some(memo)),
+                    Result::Err(e) => Result::Err(e),
+                };
+            }
+            Result::Ok(_) => {}
+            Result::Err(e) => return Result::Err(e),
+        }
+    }
+    Result::Ok(None)
+}
+
+/// Reads the memo field at `locator`, returning `None` if the memo does not have it.
+fn read_memo_field(locator: &Locator) -> Result<Option<Blob<MEMO_BLOB_SIZE>>> {
+    let mut blob = Blob::<MEMO_BLOB_SIZE>::new();
+    let result_code = unsafe {
+        get_tx_nested_field(
+            locator.as_ptr(),
+            locator.len(),
+            blob.data.as_mut_ptr(),
+            blob.data.len(),
+        )
+    };
+    match result_code {
+        code if code >= 0 => {
+            blob.len = code as usize;
+            Result::Ok(Some(blob))
+        }
+        FIELD_NOT_FOUND => Result::Ok(None),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_by_type_without_memos() {
+        // The test host reports an array length of zero.
+        assert_eq!(memo_count().unwrap(), 0);
+        assert!(find_by_type(b"command").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_get_memo_reads_every_field() {
+        // The test host reports each field as present and filling the buffer.
+        let memo = get_memo(0).unwrap();
+        assert_eq!(memo.memo_type.unwrap().len(), MEMO_BLOB_SIZE);
+        assert_eq!(memo.memo_data.unwrap().len(), MEMO_BLOB_SIZE);
+        assert_eq!(memo.memo_format.unwrap().len(), MEMO_BLOB_SIZE);
+    }
+}
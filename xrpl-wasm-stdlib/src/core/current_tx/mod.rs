@@ -61,6 +61,7 @@
 use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
 use crate::core::types::amount::{AMOUNT_SIZE, Amount};
 use crate::core::types::blob::Blob;
+use crate::core::types::nft::NFToken;
 use crate::core::types::public_key::PublicKey;
 use crate::core::types::signature::{SIGNATURE_MAX_SIZE, Signature};
 use crate::core::types::transaction_type::TransactionType;
@@ -70,7 +71,8 @@ use crate::host::field_helpers::{
     get_fixed_size_field_with_expected_bytes, get_fixed_size_field_with_expected_bytes_optional,
     get_variable_size_field, get_variable_size_field_optional,
 };
-use crate::host::{Result, get_tx_field};
+use crate::host::{Error, Result, get_tx_field};
+use crate::sfield;
 
 /// Trait for types that can be retrieved from current transaction fields.
 ///
@@ -474,5 +476,51 @@ pub fn get_field_optional<T: CurrentTxFieldGetter>(field_code: i32) -> Result<Op
     T::get_from_current_tx_optional(field_code)
 }
 
+/// Retrieves the `NFTokenID` of the current transaction as an [`NFToken`].
+///
+/// The field is carried by transactions that act on a single token, such as NFTokenBurn and
+/// NFTokenCreateOffer. NFTokenAcceptOffer does not carry it directly; it references the offers
+/// instead.
+///
+/// # Returns
+///
+/// Returns a `Result<NFToken>` where:
+/// * `Ok(NFToken)` - The token identified by the transaction
+/// * `Err(Error::FieldNotFound)` - If the transaction has no `NFTokenID` (e.g. a different type)
+/// * `Err(Error)` - If the field cannot be retrieved or has unexpected size
+#[inline]
+pub fn get_tx_nft_id() -> Result<NFToken> {
+    match get_field::<Hash256>(sfield::NFTokenID) {
+        Result::Ok(id) => Result::Ok(NFToken::from(id.0)),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Retrieves the `LimitAmount` of the current transaction.
+///
+/// Only TrustSet transactions carry this field. A trust line limit is always an issued amount:
+/// its currency and issuer name the trust line and its value is the new limit.
+///
+/// # Returns
+///
+/// Returns a `Result<Amount>` where:
+/// * `Ok(Amount::IOU { .. })` - The requested trust line limit
+/// * `Err(Error::FieldNotFound)` - If the transaction has no `LimitAmount` (i.e. is not a
+///   TrustSet)
+/// * `Err(Error::InvalidDecoding)` - If the field holds an XRP or MPT amount, which is malformed
+///   for a trust line limit
+/// * `Err(Error)` - If the field cannot be retrieved or decoded (see [`Amount::from_tx_field`])
+#[inline]
+pub fn get_tx_limit_amount() -> Result<Amount> {
+    match Amount::from_tx_field(sfield::LimitAmount) {
+        Result::Ok(amount @ Amount::IOU { .. }) => Result::Ok(amount),
+        Result::Ok(_) => Result::Err(Error::InvalidDecoding),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
 pub mod escrow_finish;
+pub mod memo;
+pub mod signer_list_set;
 pub mod traits;
+pub mod validation;
@@ -0,0 +1,174 @@
+//! # SignerListSet
+//!
+//! This module provides access to the signer configuration proposed by a SignerListSet
+//! transaction. Unlike the `SignerList` ledger object, which describes the signers currently in
+//! effect, these helpers read the `SignerQuorum` and `SignerEntries` fields of the current
+//! transaction itself.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::current_tx::signer_list_set::tx_signer_entries;
+//! use xrpl_wasm_stdlib::host::Result;
+//! # fn example() -> i32 {
+//! let mut total_weight: u32 = 0;
+//! for entry in tx_signer_entries().unwrap_or_panic() {
+//!     match entry {
+//!         Result::Ok(entry) => total_weight += entry.weight as u32,
+//!         Result::Err(e) => return e.code(),
+//!     }
+//! }
+//! # total_weight as i32
+//! # }
+//! ```
+
+use crate::core::current_tx::get_field_optional;
+use crate::core::locator::Locator;
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::host::error_codes::{FIELD_NOT_FOUND, match_result_code_with_expected_bytes};
+use crate::host::{Error, Result, get_tx_array_len, get_tx_nested_field};
+use crate::sfield;
+
+/// A single entry of the `SignerEntries` array in a SignerListSet transaction.
+///
+/// ## Derived Traits
+///
+/// - `Copy`: Efficient for this 22-byte struct, enabling implicit copying
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignerEntry {
+    /// The account whose signature contributes to the quorum.
+    pub account: AccountID,
+    /// The weight this signer's signature counts for.
+    pub weight: u16,
+}
+
+/// Iterator over the `SignerEntries` of the current transaction.
+///
+/// Each item is read from the host lazily, so every step yields a `Result<SignerEntry>`.
+/// Iteration stops after the first error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignerEntryIter {
+    index: i32,
+    len: i32,
+}
+
+impl SignerEntryIter {
+    /// Returns the number of entries that have not been yielded yet.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        (self.len - self.index) as usize
+    }
+}
+
+impl Iterator for SignerEntryIter {
+    type Item = Result<SignerEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let entry = get_signer_entry(self.index);
+        if entry.is_ok() {
+            self.index += 1;
+        } else {
+            // Do not keep hammering the host once an entry fails to decode.
+            self.index = self.len;
+        }
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining()))
+    }
+}
+
+/// Returns an iterator over the `SignerEntries` of the current SignerListSet transaction.
+///
+/// A transaction without a `SignerEntries` field (for example, a SignerListSet that deletes the
+/// signer list) produces an empty iterator rather than an error.
+///
+/// # Returns
+///
+/// Returns a `Result<SignerEntryIter>` where:
+/// * `Ok(SignerEntryIter)` - An iterator over the proposed signer entries (possibly empty)
+/// * `Err(Error)` - If the host failed to report the array length for another reason
+pub fn tx_signer_entries() -> Result<SignerEntryIter> {
+    let len = unsafe { get_tx_array_len(sfield::SignerEntries) };
+    match len {
+        len if len >= 0 => Result::Ok(SignerEntryIter { index: 0, len }),
+        FIELD_NOT_FOUND => Result::Ok(SignerEntryIter { index: 0, len: 0 }),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// Returns the `SignerQuorum` of the current SignerListSet transaction, if present.
+#[inline]
+pub fn tx_signer_quorum() -> Result<Option<u32>> {
+    get_field_optional(sfield::SignerQuorum)
+}
+
+/// Reads `SignerEntries[index]` from the current transaction.
+fn get_signer_entry(index: i32) -> Result<SignerEntry> {
+    let mut locator = Locator::new();
+    locator.pack(sfield::SignerEntries);
+    locator.pack(index);
+    locator.pack(sfield::Account);
+
+    let mut account = [0u8; ACCOUNT_ID_SIZE];
+    let result_code = unsafe {
+        get_tx_nested_field(
+            locator.as_ptr(),
+            locator.len(),
+            account.as_mut_ptr(),
+            account.len(),
+        )
+    };
+    let account = match match_result_code_with_expected_bytes(result_code, ACCOUNT_ID_SIZE, || {
+        AccountID::from(account)
+    }) {
+        Result::Ok(account) => account,
+        Result::Err(e) => return Result::Err(e),
+    };
+
+    locator.repack_last(sfield::SignerWeight);
+    let mut weight = [0u8; 2];
+    let result_code = unsafe {
+        get_tx_nested_field(
+            locator.as_ptr(),
+            locator.len(),
+            weight.as_mut_ptr(),
+            weight.len(),
+        )
+    };
+    match_result_code_with_expected_bytes(result_code, 2, || SignerEntry {
+        account,
+        weight: u16::from_le_bytes(weight),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tx_without_signer_entries_is_empty() {
+        // The test host reports an array length of zero.
+        let mut iter = tx_signer_entries().unwrap();
+        assert_eq!(iter.remaining(), 0);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_yields_each_entry_once() {
+        let iter = SignerEntryIter { index: 0, len: 3 };
+        assert_eq!(iter.remaining(), 3);
+        let mut count = 0;
+        for entry in iter {
+            assert!(entry.is_ok());
+            count += 1;
+        }
+        assert_eq!(count, 3);
+    }
+}
@@ -41,7 +41,7 @@
 use crate::core::current_tx::{get_field, get_field_optional};
 use crate::core::types::account_id::AccountID;
 use crate::core::types::amount::Amount;
-use crate::core::types::crypto_condition::{Condition, Fulfillment};
+use crate::core::types::crypto_condition::{Condition, FULFILLMENT_MAX_SIZE, Fulfillment};
 use crate::core::types::public_key::PublicKey;
 use crate::core::types::signature::Signature;
 use crate::core::types::transaction_type::TransactionType;
@@ -388,9 +388,15 @@ pub trait EscrowFinishFields: TransactionCommonFields {
         // Fulfillment fields are limited in rippled to 256 bytes, so we don't use `get_blob_field`
         // but instead just use a smaller buffer directly.
 
-        let mut buffer = [0u8; 256]; // <-- 256 is the current rippled cap.
+        let mut buffer = [0u8; FULFILLMENT_MAX_SIZE];
 
-        let result_code = unsafe { get_tx_field(sfield::Fulfillment, buffer.as_mut_ptr(), 256) };
+        let result_code = unsafe {
+            get_tx_field(
+                sfield::Fulfillment,
+                buffer.as_mut_ptr(),
+                FULFILLMENT_MAX_SIZE,
+            )
+        };
         match_result_code_optional(result_code, || {
             Some(Fulfillment {
                 data: buffer,
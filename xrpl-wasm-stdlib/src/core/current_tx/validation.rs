@@ -0,0 +1,150 @@
+//! # Transaction Shape Validation
+//!
+//! Smart escrows are usually written against one specific triggering transaction type. This
+//! module lets a contract confirm up front that the current transaction is of the expected type
+//! and carries every field that type requires, so it can fail fast with a clear error code
+//! instead of partway through its logic.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::current_tx::validation::validate_tx_shape;
+//! use xrpl_wasm_stdlib::core::types::transaction_type::TransactionType;
+//! use xrpl_wasm_stdlib::host::Result;
+//! # fn finish() -> i32 {
+//! if let Result::Err(e) = validate_tx_shape(TransactionType::EscrowFinish) {
+//!     return e.code();
+//! }
+//! # 1
+//! # }
+//! ```
+
+use crate::core::current_tx::get_field;
+use crate::core::types::amount::AMOUNT_SIZE;
+use crate::core::types::transaction_type::TransactionType;
+use crate::host::error_codes::{BUFFER_TOO_SMALL, FIELD_NOT_FOUND};
+use crate::host::{Error, Result, get_tx_field};
+use crate::sfield;
+
+/// Fields every transaction carries, regardless of type.
+const COMMON_REQUIRED_FIELDS: &[i32] = &[
+    sfield::TransactionType,
+    sfield::Account,
+    sfield::Fee,
+    sfield::Sequence,
+    sfield::SigningPubKey,
+];
+
+/// Returns the type-specific fields that must be present on a transaction of `tx_type`.
+///
+/// Only fields that rippled requires unconditionally are listed; fields that are required only in
+/// combination with flags or other optional fields are not. Transaction types without an entry
+/// return an empty slice.
+pub fn required_fields(tx_type: TransactionType) -> &'static [i32] {
+    match tx_type {
+        TransactionType::Payment => &[sfield::Destination, sfield::Amount],
+        TransactionType::EscrowCreate => &[sfield::Destination, sfield::Amount],
+        TransactionType::EscrowFinish | TransactionType::EscrowCancel => {
+            &[sfield::Owner, sfield::OfferSequence]
+        }
+        TransactionType::OfferCreate => &[sfield::TakerPays, sfield::TakerGets],
+        TransactionType::OfferCancel => &[sfield::OfferSequence],
+        TransactionType::SignerListSet => &[sfield::SignerQuorum],
+        TransactionType::PaymentChannelCreate => &[
+            sfield::Destination,
+            sfield::Amount,
+            sfield::SettleDelay,
+            sfield::PublicKey,
+        ],
+        TransactionType::PaymentChannelFund => &[sfield::Channel, sfield::Amount],
+        TransactionType::PaymentChannelClaim => &[sfield::Channel],
+        TransactionType::CheckCreate => &[sfield::Destination, sfield::SendMax],
+        TransactionType::CheckCash | TransactionType::CheckCancel => &[sfield::CheckID],
+        TransactionType::TrustSet => &[sfield::LimitAmount],
+        TransactionType::AccountDelete => &[sfield::Destination],
+        TransactionType::TicketCreate => &[sfield::TicketCount],
+        TransactionType::NFTokenMint => &[sfield::NFTokenTaxon],
+        TransactionType::NFTokenBurn => &[sfield::NFTokenID],
+        TransactionType::NFTokenCreateOffer => &[sfield::NFTokenID, sfield::Amount],
+        _ => &[],
+    }
+}
+
+/// Confirms that the current transaction is of type `expected` and carries every field that
+/// type requires (see [`required_fields`]).
+///
+/// # Returns
+///
+/// * `Ok(())` - The transaction has the expected type and all required fields
+/// * `Err(Error::InvalidParams)` - The transaction is of a different type
+/// * `Err(Error::FieldNotFound)` - A required field is missing
+/// * `Err(Error)` - Any other error reported by the host
+pub fn validate_tx_shape(expected: TransactionType) -> Result<()> {
+    match get_field::<TransactionType>(sfield::TransactionType) {
+        Result::Ok(actual) if actual == expected => {}
+        Result::Ok(_) => return Result::Err(Error::InvalidParams),
+        Result::Err(e) => return Result::Err(e),
+    }
+
+    for field_code in COMMON_REQUIRED_FIELDS
+        .iter()
+        .chain(required_fields(expected))
+    {
+        match is_field_present(*field_code) {
+            Result::Ok(true) => {}
+            Result::Ok(false) => return Result::Err(Error::FieldNotFound),
+            Result::Err(e) => return Result::Err(e),
+        }
+    }
+    Result::Ok(())
+}
+
+/// Returns whether `field_code` is present on the current transaction.
+///
+/// A buffer large enough for any fixed-size field is used; a variable-length field that does not
+/// fit still counts as present.
+fn is_field_present(field_code: i32) -> Result<bool> {
+    let mut buffer = [0u8; AMOUNT_SIZE];
+    let result_code = unsafe { get_tx_field(field_code, buffer.as_mut_ptr(), buffer.len()) };
+    match result_code {
+        code if code >= 0 => Result::Ok(true),
+        BUFFER_TOO_SMALL => Result::Ok(true),
+        FIELD_NOT_FOUND => Result::Ok(false),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_fields_escrow_finish() {
+        assert_eq!(
+            required_fields(TransactionType::EscrowFinish),
+            &[sfield::Owner, sfield::OfferSequence]
+        );
+    }
+
+    #[test]
+    fn test_required_fields_do_not_repeat_common_fields() {
+        for tx_type in [
+            TransactionType::Payment,
+            TransactionType::EscrowCreate,
+            TransactionType::SignerListSet,
+            TransactionType::PaymentChannelCreate,
+            TransactionType::AccountSet,
+        ] {
+            for field in required_fields(tx_type) {
+                assert!(!COMMON_REQUIRED_FIELDS.contains(field));
+            }
+        }
+        assert!(required_fields(TransactionType::AccountSet).is_empty());
+    }
+
+    #[test]
+    fn test_is_field_present_with_test_host() {
+        // The test host reports every field as present.
+        assert!(is_field_present(sfield::Owner).unwrap());
+    }
+}
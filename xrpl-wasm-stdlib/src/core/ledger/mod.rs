@@ -0,0 +1,289 @@
+//! # Ledger Context
+//!
+//! Accessors for ledger-wide values that are not tied to the current transaction or to any
+//! particular ledger object, such as the network's fee settings.
+//!
+//! ## Fees
+//!
+//! XRPL transaction costs are built from three related values:
+//!
+//! - **Base fee**: The reference cost, in drops, of the cheapest possible transaction. It is set
+//!   by validator fee voting and only changes on flag ledgers. See [`get_base_fee`].
+//! - **Load factor**: A multiplier (1 when the network is idle) that each server raises while it
+//!   is under load or while the open ledger is full.
+//! - **Open-ledger fee**: The cost a transaction must actually pay to be queued into the current
+//!   open ledger, i.e. `base fee × load factor`. See [`get_open_ledger_fee`].
+//!
+//! Because the load factor is local to each server and changes from moment to moment, it is not
+//! part of the validated ledger state that a smart escrow observes.
+//!
+//! ## Reserves
+//!
+//! An account must hold a minimum XRP balance: the **base reserve**, plus the **owner reserve
+//! increment** for each object it owns (its `OwnerCount`). Both are fee-voted and stored in the
+//! `FeeSettings` ledger object. See [`required_reserve`].
+//!
+//! ## Time
+//!
+//! **Time-gated logic must use the parent ledger's close time.** A smart escrow runs while the
+//! current ledger is still being assembled, and that ledger does not get a close time until
+//! consensus closes it. The only time every validator agrees on during execution is the close
+//! time of the previous (parent) ledger, which is what rippled itself uses to check fields like
+//! `FinishAfter`, `CancelAfter` and `Expiration`. See [`get_parent_close_time`].
+//!
+//! All time handling lives in the [`time`] module: [`time::now`] reads the parent close time as
+//! a [`time::RippleTime`], [`is_after`] and [`has_expired`] compare against it using rippled's
+//! boundary rules, and [`get_close_time`] exists only to make the rule above explicit, failing
+//! because the value it would return is not yet finalized. All times are in
+//! seconds since the Ripple epoch (2000-01-01T00:00:00Z).
+//!
+//! ## Snapshot
+//!
+//! [`current_context`] reads the commonly needed values (ledger sequence, parent close time,
+//! network ID and base fee) once each into a [`LedgerContext`], so code that consults several
+//! of them makes one host call per value and works from a single consistent view.
+
+use crate::core::current_tx::escrow_finish::get_current_escrow_finish;
+use crate::core::current_tx::traits::TransactionCommonFields;
+use crate::core::ledger_objects::ledger_object;
+use crate::core::types::amount::Amount;
+use crate::core::types::keylets::KeyletBytes;
+use crate::host::{
+    Error, Result, cache_ledger_obj, get_base_fee as host_get_base_fee,
+    get_ledger_sqn as host_get_ledger_sqn,
+};
+use crate::sfield;
+
+pub mod time;
+
+pub use time::{get_close_time, has_expired, is_after};
+
+use time::RippleTime;
+
+/// Keylet of the `FeeSettings` singleton: `SHA512Half(0x0065)`.
+const FEE_SETTINGS_KEYLET: KeyletBytes = [
+    0x4B, 0xC5, 0x0C, 0x9B, 0x0D, 0x85, 0x15, 0xD3, 0xEA, 0xAE, 0x1E, 0x74, 0xB2, 0x9A, 0x95, 0x80,
+    0x43, 0x46, 0xC4, 0x91, 0xEE, 0x1A, 0x95, 0xBF, 0x25, 0xE4, 0xAA, 0xB8, 0x54, 0xA6, 0xA6, 0x51,
+];
+
+/// Returns the network's reference base fee, in drops.
+///
+/// This is the static, fee-voted cost of a reference transaction and does not include any
+/// load-based escalation.
+#[inline]
+pub fn get_base_fee() -> Result<u64> {
+    let result_code = unsafe { host_get_base_fee() };
+    match result_code {
+        code if code >= 0 => Result::Ok(code as u64),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// Returns the open-ledger fee (`base fee × load factor`), in drops.
+///
+/// The host does not currently expose the server's load factor to smart escrows (see the module
+/// documentation for why), so this always returns `Error::NotImplemented`. Callers that need a
+/// fee amount should fall back to [`get_base_fee`], which is a lower bound on the open-ledger
+/// fee.
+#[inline]
+pub fn get_open_ledger_fee() -> Result<u64> {
+    Result::Err(Error::NotImplemented)
+}
+
+/// Returns the base reserve, in drops: the minimum XRP balance of an account that owns nothing.
+#[inline]
+pub fn get_reserve_base() -> Result<u64> {
+    read_fee_setting(sfield::ReserveBaseDrops, sfield::ReserveBase)
+}
+
+/// Returns the owner reserve increment, in drops: the additional XRP an account must hold for
+/// each object it owns.
+#[inline]
+pub fn get_reserve_increment() -> Result<u64> {
+    read_fee_setting(sfield::ReserveIncrementDrops, sfield::ReserveIncrement)
+}
+
+/// Returns the total reserve, in drops, of an account with `owner_count` owned objects:
+/// `reserve_base + owner_count × reserve_increment`.
+///
+/// Returns `Error::InvalidParams` if the result does not fit in a `u64`.
+#[inline]
+pub fn required_reserve(owner_count: u32) -> Result<u64> {
+    let base = match get_reserve_base() {
+        Result::Ok(base) => base,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let increment = match get_reserve_increment() {
+        Result::Ok(increment) => increment,
+        Result::Err(e) => return Result::Err(e),
+    };
+    match reserve_for(base, increment, owner_count) {
+        Some(reserve) => Result::Ok(reserve),
+        None => Result::Err(Error::InvalidParams),
+    }
+}
+
+/// Computes `base + owner_count × increment`, or `None` on overflow.
+#[inline]
+fn reserve_for(base: u64, increment: u64, owner_count: u32) -> Option<u64> {
+    increment
+        .checked_mul(owner_count as u64)
+        .and_then(|owner_reserve| owner_reserve.checked_add(base))
+}
+
+/// Reads a reserve value from `FeeSettings`.
+///
+/// Ledgers with the `XRPFees` amendment store reserves as XRP amounts in `drops_field`; older
+/// ledgers store them as `UInt32` drop counts in `legacy_field`.
+fn read_fee_setting(drops_field: i32, legacy_field: i32) -> Result<u64> {
+    let slot = match unsafe {
+        cache_ledger_obj(FEE_SETTINGS_KEYLET.as_ptr(), FEE_SETTINGS_KEYLET.len(), 0)
+    } {
+        slot if slot > 0 => slot,
+        code => return Result::Err(Error::from_code(code)),
+    };
+    match ledger_object::get_field_optional::<Amount>(slot, drops_field) {
+        Result::Ok(Some(Amount::XRP { num_drops })) if num_drops >= 0 => {
+            Result::Ok(num_drops as u64)
+        }
+        Result::Ok(Some(_)) => Result::Err(Error::InvalidDecoding),
+        Result::Ok(None) => match ledger_object::get_field::<u32>(slot, legacy_field) {
+            Result::Ok(drops) => Result::Ok(drops as u64),
+            Result::Err(e) => Result::Err(e),
+        },
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Returns the close time of the parent ledger.
+///
+/// This is the deterministic "now" for smart escrow execution (see the module documentation),
+/// and the same value as [`time::now`].
+#[inline]
+pub fn get_parent_close_time() -> Result<RippleTime> {
+    time::now()
+}
+
+/// Returns the sequence number of the ledger currently being assembled, which is the ledger
+/// the escrow's effects will be recorded in (one more than the parent ledger's).
+#[inline]
+pub fn get_ledger_sqn() -> Result<u32> {
+    let result_code = unsafe { host_get_ledger_sqn() };
+    match result_code {
+        code if code >= 0 => Result::Ok(code as u32),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// A snapshot of ledger-wide values, read by [`current_context`].
+///
+/// All values describe the view while the current ledger is still being assembled: `sequence`
+/// is that ledger's sequence, but `parent_close_time` is the close time of its parent, since the
+/// current ledger has no close time yet (see the module documentation).
+///
+/// ## Derived Traits
+///
+/// - `Copy`: Efficient for this small struct, enabling implicit copying
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LedgerContext {
+    /// The sequence number of the ledger being assembled; see [`get_ledger_sqn`].
+    pub sequence: u32,
+    /// The parent ledger's close time; see [`time::now`].
+    pub parent_close_time: RippleTime,
+    /// The network ID declared by the current transaction's `NetworkID` field.
+    ///
+    /// The host does not expose the network ID directly. Transactions must carry `NetworkID` on
+    /// networks whose ID is above 1024 and must omit it otherwise, so `None` means a network
+    /// with ID 1024 or below, such as mainnet (0), testnet (1) or devnet (2).
+    pub network_id: Option<u32>,
+    /// The reference base fee, in drops; see [`get_base_fee`].
+    pub base_fee: u64,
+}
+
+/// Reads the sequence, parent close time, network ID and base fee once each and returns them
+/// together as a [`LedgerContext`].
+///
+/// Returns the first error encountered.
+pub fn current_context() -> Result<LedgerContext> {
+    let sequence = match get_ledger_sqn() {
+        Result::Ok(sequence) => sequence,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let parent_close_time = match time::now() {
+        Result::Ok(time) => time,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let network_id = match get_current_escrow_finish().get_network_id() {
+        Result::Ok(network_id) => network_id,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let base_fee = match get_base_fee() {
+        Result::Ok(fee) => fee,
+        Result::Err(e) => return Result::Err(e),
+    };
+    Result::Ok(LedgerContext {
+        sequence,
+        parent_close_time,
+        network_id,
+        base_fee,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::NOT_IMPLEMENTED;
+
+    #[test]
+    fn test_get_base_fee() {
+        // The test host reports a base fee of 1 drop.
+        assert_eq!(get_base_fee().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_get_open_ledger_fee_is_unsupported() {
+        assert_eq!(get_open_ledger_fee().err().unwrap().code(), NOT_IMPLEMENTED);
+    }
+
+    #[test]
+    fn test_reserve_for() {
+        // 10 XRP base and 2 XRP per owned object, as on mainnet in 2024.
+        assert_eq!(reserve_for(10_000_000, 2_000_000, 0), Some(10_000_000));
+        assert_eq!(reserve_for(10_000_000, 2_000_000, 3), Some(16_000_000));
+        assert_eq!(
+            reserve_for(0, u64::MAX / u32::MAX as u64, u32::MAX),
+            Some(u64::MAX - u64::MAX % u32::MAX as u64)
+        );
+    }
+
+    #[test]
+    fn test_reserve_for_overflow() {
+        assert_eq!(reserve_for(0, u64::MAX, 2), None);
+        assert_eq!(reserve_for(u64::MAX, 1, 1), None);
+    }
+
+    #[test]
+    fn test_close_time_uses_parent_ledger() {
+        // The test host reports a parent close time of 1.
+        assert_eq!(get_parent_close_time().unwrap(), time::now().unwrap());
+        assert_eq!(
+            get_parent_close_time().unwrap(),
+            RippleTime::from_seconds(1)
+        );
+        assert_eq!(get_close_time().err().unwrap().code(), NOT_IMPLEMENTED);
+    }
+
+    #[test]
+    fn test_current_context_with_test_host() {
+        // The test host reports 1 for the sequence, parent close time and base fee.
+        let context = current_context().unwrap();
+        assert_eq!(context.sequence, get_ledger_sqn().unwrap());
+        assert_eq!(context.sequence, 1);
+        assert_eq!(context.parent_close_time, RippleTime::from_seconds(1));
+        assert_eq!(context.base_fee, 1);
+        // The test host reports every optional transaction field as present.
+        assert!(context.network_id.is_some());
+    }
+}
@@ -0,0 +1,246 @@
+//! # Ledger Time
+//!
+//! XRPL timestamps count seconds since the Ripple epoch, 2000-01-01T00:00:00Z, which is
+//! [`RIPPLE_EPOCH_OFFSET`] seconds after the Unix epoch. They are stored as `u32`, so they run
+//! until early 2136.
+//!
+//! Ledger close times are also coarse: each ledger's close time is rounded to the network's
+//! current close time resolution (between 10 and 120 seconds), so two consecutive ledgers can
+//! report the same time. Time-gated logic should compare against [`now`], the parent ledger's
+//! close time, and allow for that granularity rather than expecting second-level precision.
+//!
+//! [`now`] is the only reader of the ledger clock; [`is_after`] and [`has_expired`] build on it
+//! and follow rippled's boundary rules.
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::ledger::time::{RippleTime, now};
+//! # fn example() -> i32 {
+//! // 2025-01-01T00:00:00Z
+//! let deadline = RippleTime::from_unix(1_735_689_600).unwrap();
+//! let passed = now().unwrap_or_panic() > deadline;
+//! # passed as i32
+//! # }
+//! ```
+
+use crate::host::{Error, Result, get_parent_ledger_time};
+
+/// Seconds between the Unix epoch (1970-01-01) and the Ripple epoch (2000-01-01).
+pub const RIPPLE_EPOCH_OFFSET: u64 = 946_684_800;
+
+/// A point in time, in seconds since the Ripple epoch, as stored in fields such as
+/// `Expiration`, `FinishAfter` and `CancelAfter`.
+///
+/// ## Derived Traits
+///
+/// - `Copy`: Efficient for this 4-byte struct, enabling implicit copying
+/// - `PartialOrd, Ord`: Times compare chronologically
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RippleTime(pub u32);
+
+impl RippleTime {
+    /// The Ripple epoch itself, 2000-01-01T00:00:00Z.
+    pub const EPOCH: RippleTime = RippleTime(0);
+
+    /// Creates a time from seconds since the Ripple epoch.
+    #[inline]
+    pub const fn from_seconds(seconds: u32) -> Self {
+        RippleTime(seconds)
+    }
+
+    /// Returns the number of seconds since the Ripple epoch.
+    #[inline]
+    pub const fn as_seconds(&self) -> u32 {
+        self.0
+    }
+
+    /// Converts a Unix timestamp, returning `None` if it falls before the Ripple epoch or after
+    /// the last time a `u32` can hold.
+    #[inline]
+    pub const fn from_unix(unix_seconds: u64) -> Option<Self> {
+        match unix_to_ripple(unix_seconds) {
+            Some(seconds) => Some(RippleTime(seconds)),
+            None => None,
+        }
+    }
+
+    /// Returns this time as a Unix timestamp.
+    #[inline]
+    pub const fn to_unix(&self) -> u64 {
+        ripple_to_unix(self.0)
+    }
+
+    /// Rounds to the nearest multiple of `resolution` seconds, with halves rounding up, the way
+    /// rippled rounds ledger close times. A `resolution` of 0 leaves the time unchanged, and a
+    /// result past the end of the `u32` range saturates.
+    #[inline]
+    pub const fn round_to_resolution(&self, resolution: u32) -> Self {
+        if resolution == 0 {
+            return *self;
+        }
+        let shifted = self.0 as u64 + (resolution / 2) as u64;
+        let rounded = shifted - shifted % resolution as u64;
+        if rounded > u32::MAX as u64 {
+            RippleTime(u32::MAX)
+        } else {
+            RippleTime(rounded as u32)
+        }
+    }
+
+    /// Returns whether an object with this `Expiration` has expired as of `close_time`.
+    ///
+    /// Expiration is inclusive: an object is expired once the close time reaches it, matching
+    /// rippled's checks for offers, checks and payment channels.
+    #[inline]
+    pub const fn is_expired_at(&self, close_time: RippleTime) -> bool {
+        close_time.0 >= self.0
+    }
+}
+
+impl From<u32> for RippleTime {
+    fn from(seconds: u32) -> Self {
+        RippleTime(seconds)
+    }
+}
+
+impl From<RippleTime> for u32 {
+    fn from(time: RippleTime) -> Self {
+        time.0
+    }
+}
+
+/// Converts seconds since the Ripple epoch to a Unix timestamp.
+#[inline]
+pub const fn ripple_to_unix(ripple_seconds: u32) -> u64 {
+    ripple_seconds as u64 + RIPPLE_EPOCH_OFFSET
+}
+
+/// Converts a Unix timestamp to seconds since the Ripple epoch, returning `None` if it falls
+/// before the Ripple epoch or does not fit in a `u32`.
+#[inline]
+pub const fn unix_to_ripple(unix_seconds: u64) -> Option<u32> {
+    if unix_seconds < RIPPLE_EPOCH_OFFSET {
+        return None;
+    }
+    let ripple_seconds = unix_seconds - RIPPLE_EPOCH_OFFSET;
+    if ripple_seconds > u32::MAX as u64 {
+        None
+    } else {
+        Some(ripple_seconds as u32)
+    }
+}
+
+/// Returns the deterministic "now" of a smart escrow: the parent ledger's close time.
+///
+/// See [`crate::core::ledger`] for why the current ledger's close time cannot be used.
+#[inline]
+pub fn now() -> Result<RippleTime> {
+    let result_code = unsafe { get_parent_ledger_time() };
+    match result_code {
+        code if code >= 0 => Result::Ok(RippleTime(code as u32)),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// Returns the close time of the ledger currently being assembled.
+///
+/// That ledger has not closed yet, so its close time is not finalized while a smart escrow runs
+/// and different validators could not agree on it. This always returns
+/// `Error::NotImplemented`; use [`now`] instead.
+#[inline]
+pub fn get_close_time() -> Result<RippleTime> {
+    Result::Err(Error::NotImplemented)
+}
+
+/// Returns whether the parent ledger closed strictly after `time`.
+///
+/// This matches how rippled checks `FinishAfter` and `CancelAfter`: an escrow with
+/// `FinishAfter = t` can be finished once the parent close time is greater than `t`.
+#[inline]
+pub fn is_after(time: RippleTime) -> Result<bool> {
+    match now() {
+        Result::Ok(close_time) => Result::Ok(close_time > time),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Returns whether an object with the given `Expiration` has expired as of [`now`].
+///
+/// See [`RippleTime::is_expired_at`] for the boundary rule.
+#[inline]
+pub fn has_expired(expiration: RippleTime) -> Result<bool> {
+    match now() {
+        Result::Ok(close_time) => Result::Ok(expiration.is_expired_at(close_time)),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::NOT_IMPLEMENTED;
+
+    #[test]
+    fn test_epoch_boundaries() {
+        assert_eq!(ripple_to_unix(0), 946_684_800);
+        assert_eq!(unix_to_ripple(946_684_800), Some(0));
+        assert_eq!(unix_to_ripple(946_684_799), None);
+        assert_eq!(unix_to_ripple(0), None);
+
+        // The last representable time, in 2136.
+        assert_eq!(ripple_to_unix(u32::MAX), 946_684_800 + u32::MAX as u64);
+        assert_eq!(
+            unix_to_ripple(946_684_800 + u32::MAX as u64),
+            Some(u32::MAX)
+        );
+        assert_eq!(unix_to_ripple(946_684_801 + u32::MAX as u64), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // 2025-01-01T00:00:00Z
+        let time = RippleTime::from_unix(1_735_689_600).unwrap();
+        assert_eq!(time.as_seconds(), 789_004_800);
+        assert_eq!(time.to_unix(), 1_735_689_600);
+        assert!(RippleTime::EPOCH < time);
+    }
+
+    #[test]
+    fn test_round_to_resolution() {
+        let time = |seconds| RippleTime::from_seconds(seconds);
+        assert_eq!(time(104).round_to_resolution(10), time(100));
+        // Halves round up.
+        assert_eq!(time(105).round_to_resolution(10), time(110));
+        assert_eq!(time(110).round_to_resolution(10), time(110));
+        assert_eq!(time(59).round_to_resolution(120), time(0));
+        assert_eq!(time(60).round_to_resolution(120), time(120));
+        assert_eq!(time(7).round_to_resolution(0), time(7));
+        assert_eq!(time(u32::MAX).round_to_resolution(10), time(u32::MAX));
+    }
+
+    #[test]
+    fn test_expiry_is_inclusive() {
+        let expiration = RippleTime::from_seconds(100);
+        assert!(!expiration.is_expired_at(RippleTime::from_seconds(99)));
+        assert!(expiration.is_expired_at(RippleTime::from_seconds(100)));
+    }
+
+    #[test]
+    fn test_now_reads_parent_close_time() {
+        // The test host reports a parent close time of 1.
+        assert_eq!(now().unwrap(), RippleTime::from_seconds(1));
+        assert_eq!(get_close_time().err().unwrap().code(), NOT_IMPLEMENTED);
+    }
+
+    #[test]
+    fn test_time_comparison_boundaries() {
+        let time = |seconds| RippleTime::from_seconds(seconds);
+        assert!(is_after(time(0)).unwrap());
+        assert!(!is_after(time(1)).unwrap());
+        assert!(has_expired(time(1)).unwrap());
+        assert!(!has_expired(time(2)).unwrap());
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::ledger_objects::traits::{CheckFields, LedgerObjectCommonFields};
+
+/// A Check ledger object (a deferred payment the destination can cash) loaded into a host slot.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(C)]
+pub struct Check {
+    pub slot_num: i32,
+}
+
+impl LedgerObjectCommonFields for Check {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl CheckFields for Check {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_expired_with_test_host() {
+        // The test host reports every field as present, so this exercises the comparison path.
+        let check = Check { slot_num: 1 };
+        assert!(check.get_expiration().unwrap().is_some());
+        assert!(check.is_expired().is_ok());
+    }
+}
@@ -1,4 +1,22 @@
+//! # Current Escrow
+//!
+//! The escrow ledger object whose `FinishFunction` is running. Its fields are read with
+//! `get_current_ledger_obj_field`, so they describe the escrow as stored on the ledger: for
+//! example, [`CurrentEscrowFields::get_amount`] is the amount locked in the escrow.
+//!
+//! This is a different object from the transaction that triggered the run, which is read with
+//! `get_tx_field` through [`EscrowFinish`](crate::core::current_tx::escrow_finish::EscrowFinish).
+//! Some field names appear on both with different meanings: the escrow's `Account` is its owner
+//! while the transaction's is whoever submitted the finish, and the escrow's `Sequence` is that
+//! of the `EscrowCreate` while the transaction's is the finisher's. An `EscrowFinish` carries no
+//! `Amount` at all, so the locked amount can only come from here.
+
+use crate::core::current_tx::escrow_finish::get_current_escrow_finish;
+use crate::core::current_tx::traits::EscrowFinishFields;
 use crate::core::ledger_objects::traits::{CurrentEscrowFields, CurrentLedgerObjectCommonFields};
+use crate::core::types::keylets::escrow_keylet;
+use crate::core::types::uint::Hash256;
+use crate::host::{Error, Result};
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 #[repr(C)]
@@ -12,3 +30,65 @@ impl CurrentEscrowFields for CurrentEscrow {}
 pub fn get_current_escrow() -> CurrentEscrow {
     CurrentEscrow
 }
+
+/// Returns the keylet (ledger index) of the escrow currently executing, so the escrow can
+/// refer to itself, for example from a transaction it builds.
+///
+/// An escrow's keylet is `SHA512Half(0x0075 || owner || sequence)`, where `sequence` is that of
+/// the `EscrowCreate` transaction. The owner is the escrow's `Account`; the sequence is read
+/// from the escrow's `Sequence` field, or, for escrows that do not record it, from the
+/// `OfferSequence` of the `EscrowFinish` transaction, which must name this escrow.
+pub fn get_keylet() -> Result<Hash256> {
+    let escrow = get_current_escrow();
+    let owner = match escrow.get_account() {
+        Result::Ok(owner) => owner,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let sequence = match escrow.get_sequence() {
+        Result::Ok(sequence) => sequence,
+        Result::Err(Error::FieldNotFound) => {
+            match get_current_escrow_finish().get_offer_sequence() {
+                Result::Ok(sequence) => sequence,
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+        Result::Err(e) => return Result::Err(e),
+    };
+    // The host takes the sequence as an `i32`; the cast keeps its bit pattern.
+    match escrow_keylet(&owner, sequence as i32) {
+        Result::Ok(keylet) => Result::Ok(Hash256::from(keylet)),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_keylet_with_test_host() {
+        assert!(get_keylet().is_ok());
+    }
+
+    #[test]
+    fn test_escrow_and_transaction_fields_in_one_finish() {
+        use crate::core::current_tx::traits::TransactionCommonFields;
+        use crate::core::types::account_id::AccountID;
+        use crate::core::types::amount::Amount;
+
+        let escrow = get_current_escrow();
+        let tx = get_current_escrow_finish();
+
+        // The locked amount comes from the escrow object; the fee from the transaction, which
+        // the test host zero-fills, so it always decodes as zero XRP.
+        assert!(escrow.get_amount().is_ok());
+        assert_eq!(tx.get_fee().unwrap(), Amount::XRP { num_drops: 0 });
+
+        // Same field names, different objects: the test host fills current ledger object fields
+        // with a non-zero pattern, so reading the wrong object would show here.
+        assert_eq!(tx.get_account().unwrap(), AccountID([0; 20]));
+        assert_ne!(escrow.get_account().unwrap(), tx.get_account().unwrap());
+        assert_eq!(tx.get_sequence().unwrap(), 0);
+        assert_ne!(escrow.get_sequence().unwrap(), tx.get_sequence().unwrap());
+    }
+}
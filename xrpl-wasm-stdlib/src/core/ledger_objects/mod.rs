@@ -1,6 +1,11 @@
 pub mod account_root;
+pub mod check;
 pub mod current_escrow;
 pub mod escrow;
+pub mod nft;
+pub mod offer;
+pub mod ripple_state;
+pub mod slot;
 pub mod traits;
 
 use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
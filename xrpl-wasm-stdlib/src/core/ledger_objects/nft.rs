@@ -0,0 +1,516 @@
+//! # NFToken Ownership
+//!
+//! Helpers for checking NFToken ownership against the ledger. NFTokens are stored in
+//! `NFTokenPage` ledger objects belonging to their owner, so ownership is checked by asking the
+//! host to look the token up in a specific account's pages.
+//!
+//! Offers to buy or sell an NFToken are `NFTokenOffer` objects listed in per-token directories,
+//! which [`lowest_sell_offer`] walks.
+//!
+//! [`serial_is_plausible`] checks an NFTokenID from untrusted input against its issuer's mint
+//! counter, catching IDs whose serial was never assigned.
+
+use crate::core::budget::StepBudget;
+use crate::core::ledger::time::{RippleTime, now};
+use crate::core::ledger_objects::account_root::AccountRoot;
+use crate::core::ledger_objects::ledger_object;
+use crate::core::ledger_objects::traits::AccountFields;
+use crate::core::locator::Locator;
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::amount::{Amount, SortableAmount};
+use crate::core::types::keylets::{KeyletBytes, XRPL_KEYLET_SIZE, account_keylet, sha512_half};
+use crate::core::types::nft::{NFT_ID_SIZE, NFT_URI_MAX_SIZE};
+use crate::core::types::uint::Hash256;
+use crate::host;
+use crate::host::error_codes::{LEDGER_OBJ_NOT_FOUND, match_result_code_with_expected_bytes};
+use crate::host::{Error, Result};
+use crate::sfield;
+use crate::types::NFT;
+
+/// Returns whether `owner` currently holds `nft`.
+///
+/// # Arguments
+///
+/// * `owner` - The account expected to hold the NFToken
+/// * `nft` - The NFToken to look up. A raw `[u8; 32]` identifier can be converted with
+///   `NFT::from(bytes)`.
+///
+/// # Returns
+///
+/// * `Ok(true)` - The NFToken is in one of `owner`'s NFToken pages
+/// * `Ok(false)` - The host could not find the NFToken for `owner`
+/// * `Err(Error)` - If the host function fails for any other reason
+///
+/// # Example
+///
+/// ```no_run
+/// use xrpl_wasm_stdlib::core::ledger_objects::nft::is_nft_owned_by;
+/// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+/// use xrpl_wasm_stdlib::types::NFT;
+/// # let destination = AccountID::from([0u8; 20]);
+/// let nft_id = [0u8; 32];
+/// let owned = is_nft_owned_by(&destination, &NFT::from(nft_id)).unwrap_or_panic();
+/// ```
+pub fn is_nft_owned_by(owner: &AccountID, nft: &NFT) -> Result<bool> {
+    let mut uri_buf = [0u8; NFT_URI_MAX_SIZE];
+    let result_code = unsafe {
+        host::get_nft(
+            owner.as_ptr(),
+            owner.len(),
+            nft.as_ptr(),
+            nft.len(),
+            uri_buf.as_mut_ptr(),
+            uri_buf.len(),
+        )
+    };
+
+    match result_code {
+        // A token without a URI is still owned, so zero bytes written is a success.
+        code if code >= 0 => Result::Ok(true),
+        LEDGER_OBJ_NOT_FOUND => Result::Ok(false),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// Returns whether `owner` currently holds every NFToken in `ids`.
+///
+/// Tokens are checked in order and the check stops at the first one `owner` does not hold, so
+/// put the tokens most likely to be missing first. An empty `ids` returns `Ok(true)`.
+///
+/// # Returns
+///
+/// * `Ok(true)` - Every NFToken is in one of `owner`'s NFToken pages
+/// * `Ok(false)` - At least one NFToken is not held by `owner`
+/// * `Err(Error)` - If the host function fails for any other reason while checking a token
+///
+/// # Example
+///
+/// ```no_run
+/// use xrpl_wasm_stdlib::core::ledger_objects::nft::owns_all;
+/// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+/// use xrpl_wasm_stdlib::types::NFT;
+/// # let destination = AccountID::from([0u8; 20]);
+/// let required = [NFT::from([1u8; 32]), NFT::from([2u8; 32])];
+/// let qualifies = owns_all(&destination, &required).unwrap_or_panic();
+/// ```
+pub fn owns_all(owner: &AccountID, ids: &[NFT]) -> Result<bool> {
+    for nft in ids {
+        match is_nft_owned_by(owner, nft) {
+            Result::Ok(true) => {}
+            Result::Ok(false) => return Result::Ok(false),
+            Result::Err(e) => return Result::Err(e),
+        }
+    }
+    Result::Ok(true)
+}
+
+/// Ledger namespace of the directory listing an NFToken's sell offers (`'i'`).
+const NFTOKEN_SELL_OFFERS_SPACE: u16 = b'i' as u16;
+/// Ledger namespace of directory pages after the first (`'d'`).
+const DIR_NODE_SPACE: u16 = b'd' as u16;
+
+/// Returns the amount of the cheapest open sell offer for `nft`, or `None` if it is not listed.
+///
+/// This walks every page of the token's sell-offer directory and loads each offer, so the cost
+/// grows with the number of offers. Offers that have expired as of the parent ledger's close
+/// time are skipped, since they can no longer be accepted. Offers restricted to a specific
+/// buyer (with a `Destination`) are included.
+///
+/// # Returns
+///
+/// * `Ok(Some(Amount))` - The lowest asking price
+/// * `Ok(None)` - The token has no unexpired sell offers
+/// * `Err(Error::InvalidParams)` - Offers are priced in different assets, so there is no single
+///   cheapest offer
+/// * `Err(Error)` - Any error while walking the directory or reading an offer
+///
+/// # Example
+///
+/// ```no_run
+/// use xrpl_wasm_stdlib::core::ledger_objects::nft::lowest_sell_offer;
+/// use xrpl_wasm_stdlib::core::types::amount::Amount;
+/// use xrpl_wasm_stdlib::types::NFT;
+/// # let nft = NFT::from([0u8; 32]);
+/// let listed_cheaply = matches!(
+///     lowest_sell_offer(&nft).unwrap_or_panic(),
+///     Some(Amount::XRP { num_drops }) if num_drops <= 10_000_000
+/// );
+/// ```
+pub fn lowest_sell_offer(nft: &NFT) -> Result<Option<Amount>> {
+    let mut preimage = [0u8; 2 + NFT_ID_SIZE];
+    preimage[..2].copy_from_slice(&NFTOKEN_SELL_OFFERS_SPACE.to_be_bytes());
+    preimage[2..].copy_from_slice(nft.as_ref());
+    let root = match sha512_half(&preimage) {
+        Result::Ok(root) => root,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let close_time = match now() {
+        Result::Ok(close_time) => close_time,
+        Result::Err(e) => return Result::Err(e),
+    };
+
+    let mut page_key = root;
+    let mut page_index = 0u64;
+    let mut dir_slot = 0;
+    let mut offer_slot = 0;
+    let mut lowest: Option<Amount> = None;
+    loop {
+        dir_slot =
+            match unsafe { host::cache_ledger_obj(page_key.as_ptr(), page_key.len(), dir_slot) } {
+                slot if slot > 0 => slot,
+                LEDGER_OBJ_NOT_FOUND if page_index == 0 => return Result::Ok(None),
+                code => return Result::Err(Error::from_code(code)),
+            };
+        let len = unsafe { host::get_ledger_obj_array_len(dir_slot, sfield::Indexes) };
+        if len < 0 {
+            return Result::Err(Error::from_code(len));
+        }
+
+        for index in 0..len {
+            let offer_key = match read_directory_entry(dir_slot, index) {
+                Result::Ok(key) => key,
+                Result::Err(e) => return Result::Err(e),
+            };
+            offer_slot = match unsafe {
+                host::cache_ledger_obj(offer_key.as_ptr(), offer_key.len(), offer_slot)
+            } {
+                slot if slot > 0 => slot,
+                code => return Result::Err(Error::from_code(code)),
+            };
+            match ledger_object::get_field_optional::<u32>(offer_slot, sfield::Expiration) {
+                Result::Ok(Some(expiration))
+                    if RippleTime::from_seconds(expiration).is_expired_at(close_time) =>
+                {
+                    continue;
+                }
+                Result::Ok(_) => {}
+                Result::Err(e) => return Result::Err(e),
+            }
+            let amount = match ledger_object::get_field::<Amount>(offer_slot, sfield::Amount) {
+                Result::Ok(amount) => amount,
+                Result::Err(e) => return Result::Err(e),
+            };
+            lowest = match lowest {
+                None => Some(amount),
+                Some(current) if !current.is_same_asset(&amount) => {
+                    return Result::Err(Error::InvalidParams);
+                }
+                Some(current) => {
+                    let current = SortableAmount(current);
+                    let candidate = SortableAmount(amount);
+                    Some(core::cmp::min(current, candidate).0)
+                }
+            };
+        }
+
+        match ledger_object::get_field_optional::<u64>(dir_slot, sfield::IndexNext) {
+            Result::Ok(None) | Result::Ok(Some(0)) => return Result::Ok(lowest),
+            // Pages are chained in increasing page order; anything else would loop.
+            Result::Ok(Some(next)) if next > page_index => page_index = next,
+            Result::Ok(Some(_)) => return Result::Err(Error::InvalidDecoding),
+            Result::Err(e) => return Result::Err(e),
+        }
+        page_key = match sha512_half(&directory_page_preimage(&root, page_index)) {
+            Result::Ok(key) => key,
+            Result::Err(e) => return Result::Err(e),
+        };
+    }
+}
+
+/// Returns the preimage of the key of page `page_index` of the directory rooted at `root`.
+fn directory_page_preimage(root: &KeyletBytes, page_index: u64) -> [u8; 2 + XRPL_KEYLET_SIZE + 8] {
+    let mut preimage = [0u8; 2 + XRPL_KEYLET_SIZE + 8];
+    preimage[..2].copy_from_slice(&DIR_NODE_SPACE.to_be_bytes());
+    preimage[2..2 + XRPL_KEYLET_SIZE].copy_from_slice(root);
+    preimage[2 + XRPL_KEYLET_SIZE..].copy_from_slice(&page_index.to_be_bytes());
+    preimage
+}
+
+/// Reads `Indexes[index]` from the directory page in `slot`.
+fn read_directory_entry(slot: i32, index: i32) -> Result<KeyletBytes> {
+    let mut locator = Locator::new();
+    locator.pack(sfield::Indexes);
+    locator.pack(index);
+
+    let mut key = [0u8; XRPL_KEYLET_SIZE];
+    let result_code = unsafe {
+        host::get_ledger_obj_nested_field(
+            slot,
+            locator.as_ptr(),
+            locator.len(),
+            key.as_mut_ptr(),
+            key.len(),
+        )
+    };
+    match_result_code_with_expected_bytes(result_code, XRPL_KEYLET_SIZE, || key)
+}
+
+/// Returns how many NFToken serials `issuer` has used: its `FirstNFTokenSequence` plus its
+/// `MintedNFTokens`, each zero if absent, read from the issuer's AccountRoot.
+///
+/// Every NFToken the issuer has minted has a serial below this value; see
+/// [`NFToken::serial_within`](crate::core::types::nft::NFToken::serial_within).
+///
+/// Returns `Error::LedgerObjNotFound` if the issuer's account does not exist, which can happen
+/// for a forged ID or for a genuine token whose issuer has since deleted its account.
+pub fn issuer_minted_count(issuer: &AccountID) -> Result<u32> {
+    let keylet = match account_keylet(issuer) {
+        Result::Ok(keylet) => keylet,
+        Result::Err(e) => return Result::Err(e),
+    };
+    let slot = unsafe { host::cache_ledger_obj(keylet.as_ptr(), keylet.len(), 0) };
+    if slot < 0 {
+        return Result::Err(Error::from_code(slot));
+    }
+    let account = AccountRoot { slot_num: slot };
+    let first = match account.first_nf_token_sequence() {
+        Result::Ok(first) => first.unwrap_or(0),
+        Result::Err(e) => return Result::Err(e),
+    };
+    let minted = match account.minted_nf_tokens() {
+        Result::Ok(minted) => minted.unwrap_or(0),
+        Result::Err(e) => return Result::Err(e),
+    };
+    Result::Ok(first.saturating_add(minted))
+}
+
+/// Returns whether `nft`'s serial is one its issuer could have assigned, decoding the issuer
+/// and serial from the ID and reading the issuer's AccountRoot with [`issuer_minted_count`].
+///
+/// Use this to reject forged NFTokenIDs from untrusted input, such as a memo. A `true` result
+/// does not prove the token exists; use [`is_nft_owned_by`] for that.
+pub fn serial_is_plausible(nft: &NFT) -> Result<bool> {
+    match issuer_minted_count(&nft.issuer_local()) {
+        Result::Ok(count) => Result::Ok(nft.serial_within(count)),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Returns an iterator over the NFTokens `owner` holds, visiting at most `max_steps` tokens and
+/// pages.
+///
+/// NFTokens live in a chain of `NFTokenPage` objects keyed by the owner. The iterator starts at
+/// the owner's last page and follows each page's `PreviousPageMin` link, reusing a single host
+/// slot for every page. Tokens are yielded page by page, so they are not globally sorted.
+///
+/// Each token read and each page load consumes one step. If the budget runs out while tokens
+/// remain, the iterator yields a final `Err(Error::BudgetExhausted)` and
+/// [`OwnedNftIter::is_truncated`] returns `true`, so a large collection can never be mistaken
+/// for a complete one. An owner without any NFTokens yields nothing.
+///
+/// # Example
+///
+/// ```no_run
+/// use xrpl_wasm_stdlib::core::ledger_objects::nft::owned_nfts;
+/// use xrpl_wasm_stdlib::core::types::account_id::AccountID;
+/// use xrpl_wasm_stdlib::host::Result;
+/// # fn count(owner: AccountID) -> i32 {
+/// let mut total = 0;
+/// for nft in owned_nfts(&owner, 64) {
+///     match nft {
+///         Result::Ok(_) => total += 1,
+///         // Includes `Error::BudgetExhausted` when the owner has too many tokens.
+///         Result::Err(e) => return e.code(),
+///     }
+/// }
+/// # total
+/// # }
+/// ```
+pub fn owned_nfts(owner: &AccountID, max_steps: u32) -> OwnedNftIter {
+    // The last page of an owner's chain is keyed by the owner followed by 96 one bits.
+    let mut last_page: KeyletBytes = [0xFF; XRPL_KEYLET_SIZE];
+    last_page[..ACCOUNT_ID_SIZE].copy_from_slice(&owner.0);
+    OwnedNftIter {
+        next_page: Some(last_page),
+        slot: 0,
+        index: 0,
+        len: 0,
+        is_first_page: true,
+        budget: StepBudget::new(max_steps),
+        done: false,
+    }
+}
+
+/// Iterator over the NFTokens an account holds. See [`owned_nfts`].
+///
+/// Iteration stops after the first error.
+#[derive(Debug, Clone)]
+pub struct OwnedNftIter {
+    /// Key of the next page to load, if any.
+    next_page: Option<KeyletBytes>,
+    /// Host slot holding the current page, or 0 before the first page is loaded.
+    slot: i32,
+    index: i32,
+    len: i32,
+    is_first_page: bool,
+    budget: StepBudget,
+    done: bool,
+}
+
+impl OwnedNftIter {
+    /// Returns `true` if iteration was cut short because the step budget ran out.
+    #[inline]
+    pub fn is_truncated(&self) -> bool {
+        self.budget.is_exhausted()
+    }
+
+    /// Loads the page with key `page`, returning `Ok(false)` if the owner has no pages at all.
+    fn load_page(&mut self, page: &KeyletBytes) -> Result<bool> {
+        let slot = unsafe { host::cache_ledger_obj(page.as_ptr(), page.len(), self.slot) };
+        match slot {
+            slot if slot > 0 => self.slot = slot,
+            LEDGER_OBJ_NOT_FOUND if self.is_first_page => return Result::Ok(false),
+            code => return Result::Err(Error::from_code(code)),
+        }
+        self.is_first_page = false;
+
+        let len = unsafe { host::get_ledger_obj_array_len(self.slot, sfield::NFTokens) };
+        if len < 0 {
+            return Result::Err(Error::from_code(len));
+        }
+        self.index = 0;
+        self.len = len;
+
+        self.next_page = match ledger_object::get_field_optional::<Hash256>(
+            self.slot,
+            sfield::PreviousPageMin,
+        ) {
+            // A zero link means there is no previous page.
+            Result::Ok(Some(previous)) if previous.is_zero() => None,
+            // Pages are chained in strictly decreasing key order; anything else would loop.
+            Result::Ok(Some(previous)) if previous.0 < *page => Some(previous.0),
+            Result::Ok(Some(_)) => return Result::Err(Error::InvalidDecoding),
+            Result::Ok(None) => None,
+            Result::Err(e) => return Result::Err(e),
+        };
+        Result::Ok(true)
+    }
+
+    /// Reads `NFTokens[index].NFTokenID` from the current page.
+    fn read_token(&self, index: i32) -> Result<NFT> {
+        let mut locator = Locator::new();
+        locator.pack(sfield::NFTokens);
+        locator.pack(index);
+        locator.pack(sfield::NFTokenID);
+
+        let mut id = [0u8; NFT_ID_SIZE];
+        let result_code = unsafe {
+            host::get_ledger_obj_nested_field(
+                self.slot,
+                locator.as_ptr(),
+                locator.len(),
+                id.as_mut_ptr(),
+                id.len(),
+            )
+        };
+        match_result_code_with_expected_bytes(result_code, NFT_ID_SIZE, || NFT::from(id))
+    }
+
+    /// Consumes one step, or ends iteration with `Error::BudgetExhausted`.
+    fn step(&mut self) -> Option<Result<NFT>> {
+        if self.budget.try_step() {
+            return None;
+        }
+        self.done = true;
+        Some(Result::Err(Error::BudgetExhausted))
+    }
+}
+
+impl Iterator for OwnedNftIter {
+    type Item = Result<NFT>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            if self.index < self.len {
+                if let Some(exhausted) = self.step() {
+                    return Some(exhausted);
+                }
+                let token = self.read_token(self.index);
+                self.index += 1;
+                self.done = token.is_err();
+                return Some(token);
+            }
+
+            let page = self.next_page.take()?;
+            if let Some(exhausted) = self.step() {
+                return Some(exhausted);
+            }
+            match self.load_page(&page) {
+                Result::Ok(true) => {}
+                Result::Ok(false) => self.done = true,
+                Result::Err(e) => {
+                    self.done = true;
+                    return Some(Result::Err(e));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::nft::NFToken;
+
+    #[test]
+    fn test_is_nft_owned_by_accepts_alias_and_token() {
+        let owner = AccountID::from([1u8; 20]);
+        let nft_id = [2u8; 32];
+
+        // Both spellings name the same type, so either can be passed.
+        let from_alias: NFT = nft_id.into();
+        let token = NFToken::new(nft_id);
+
+        assert!(is_nft_owned_by(&owner, &from_alias).unwrap());
+        assert!(is_nft_owned_by(&owner, &token).unwrap());
+    }
+
+    #[test]
+    fn test_serial_is_plausible_with_test_host() {
+        assert!(issuer_minted_count(&AccountID::from([1u8; 20])).is_ok());
+        assert!(serial_is_plausible(&NFT::from([2u8; 32])).is_ok());
+    }
+
+    #[test]
+    fn test_owns_all() {
+        let owner = AccountID::from([1u8; 20]);
+        assert!(owns_all(&owner, &[]).unwrap());
+        // The test host reports every token as owned.
+        assert!(owns_all(&owner, &[NFToken::new([2u8; 32]), NFToken::new([3u8; 32])]).unwrap());
+    }
+
+    #[test]
+    fn test_owned_nfts_stops_with_error_when_budget_runs_out() {
+        use crate::host::error_codes::BUDGET_EXHAUSTED;
+
+        let mut iter = owned_nfts(&AccountID::from([1u8; 20]), 0);
+        assert_eq!(iter.next().unwrap().err().unwrap().code(), BUDGET_EXHAUSTED);
+        assert!(iter.is_truncated());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_owned_nfts_always_terminates() {
+        // The test host reports every page as present and empty, with an arbitrary
+        // `PreviousPageMin`. Whatever the link, iteration must end in a single error rather
+        // than loop.
+        let mut iter = owned_nfts(&AccountID::from([1u8; 20]), 10);
+        let mut errors = 0;
+        for item in iter.by_ref() {
+            assert!(item.is_err());
+            errors += 1;
+        }
+        assert_eq!(errors, 1);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_directory_page_preimage() {
+        let root = [0xAA; XRPL_KEYLET_SIZE];
+        let preimage = directory_page_preimage(&root, 0x0102);
+        assert_eq!(&preimage[..2], b"\x00d");
+        assert_eq!(&preimage[2..34], &root);
+        assert_eq!(&preimage[34..], &[0, 0, 0, 0, 0, 0, 0x01, 0x02]);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::core::ledger_objects::traits::{LedgerObjectCommonFields, OfferFields};
+
+/// An Offer ledger object (an open order in the decentralized exchange) loaded into a host
+/// slot.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(C)]
+pub struct Offer {
+    pub slot_num: i32,
+}
+
+impl LedgerObjectCommonFields for Offer {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl OfferFields for Offer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ledger::time::RippleTime;
+
+    #[test]
+    fn test_book_fields_with_test_host() {
+        let offer = Offer { slot_num: 1 };
+        assert!(offer.get_book_directory().is_ok());
+        assert!(offer.get_book_node().is_ok());
+    }
+
+    #[test]
+    fn test_identity_fields_with_test_host() {
+        let offer = Offer { slot_num: 1 };
+        assert!(offer.get_account().is_ok());
+        assert!(offer.get_sequence().is_ok());
+    }
+
+    #[test]
+    fn test_expiry_boundary() {
+        let time = |seconds| RippleTime::from_seconds(seconds);
+        assert!(!time(100).is_expired_at(time(99)));
+        // An offer expires at, not after, its expiration time.
+        assert!(time(100).is_expired_at(time(100)));
+        assert!(time(100).is_expired_at(time(101)));
+    }
+}
@@ -0,0 +1,47 @@
+use crate::core::ledger_objects::traits::{LedgerObjectCommonFields, RippleStateFields};
+
+/// A RippleState ledger object (a trust line between two accounts) loaded into a host slot.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(C)]
+pub struct RippleState {
+    pub slot_num: i32,
+}
+
+impl LedgerObjectCommonFields for RippleState {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+impl RippleStateFields for RippleState {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ledger_objects::traits::{QUALITY_ONE, TrustLineSide, non_default_quality};
+    use crate::core::types::account_id::AccountID;
+
+    #[test]
+    fn test_quality_getters_with_test_host() {
+        let line = RippleState { slot_num: 1 };
+        assert!(line.get_quality_in(TrustLineSide::Low).is_ok());
+        assert!(line.get_quality_out(TrustLineSide::High).is_ok());
+    }
+
+    #[test]
+    fn test_default_quality_is_none() {
+        assert_eq!(non_default_quality(None), None);
+        assert_eq!(non_default_quality(Some(0)), None);
+        assert_eq!(non_default_quality(Some(QUALITY_ONE)), None);
+        // A 0.5% fee on incoming balances.
+        assert_eq!(non_default_quality(Some(995_000_000)), Some(995_000_000));
+    }
+
+    #[test]
+    fn test_trust_line_side() {
+        let low = AccountID::from([1u8; 20]);
+        let high = AccountID::from([2u8; 20]);
+        assert_eq!(TrustLineSide::of(&low, &high), TrustLineSide::Low);
+        assert_eq!(TrustLineSide::of(&high, &low), TrustLineSide::High);
+    }
+}
@@ -0,0 +1,349 @@
+//! # Generic Ledger Object Slots
+//!
+//! This module provides [`ObjectSlot`], a handle to any ledger object cached in a host slot,
+//! regardless of its ledger entry type, plus [`iter_fields`] for walking the fields of a
+//! serialized STObject.
+//!
+//! The host exposes cached objects one field at a time (by sfield code), never as raw bytes, so
+//! field iteration works on a serialized object supplied by the caller (for example, bytes
+//! carried in a memo or a `Blob` field). Each yielded field code uses the same
+//! `(type_code << 16) | field_code` encoding as the constants in [`crate::sfield`].
+//!
+//! ## Example
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::ledger_objects::slot::iter_fields;
+//! use xrpl_wasm_stdlib::host::Result;
+//! use xrpl_wasm_stdlib::host::trace::{DataRepr, trace_data, trace_num};
+//! # let serialized: &[u8] = &[];
+//! for field in iter_fields(serialized) {
+//!     match field {
+//!         Result::Ok((code, value)) => {
+//!             let _ = trace_num("field:", code as i64);
+//!             let _ = trace_data("  value:", value, DataRepr::AsHex);
+//!         }
+//!         Result::Err(e) => {
+//!             let _ = trace_num("malformed object:", e.code() as i64);
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::core::ledger_objects::ledger_object;
+use crate::core::ledger_objects::traits::LedgerObjectCommonFields;
+use crate::core::serialize::{ARRAY_END_CODE, OBJECT_END_CODE, read_field};
+use crate::core::types::account_id::AccountID;
+use crate::core::types::amount::Amount;
+use crate::core::types::blob::Blob;
+use crate::core::types::keylets::KeyletBytes;
+use crate::core::types::uint::Hash256;
+use crate::host;
+use crate::host::error_codes::{BUFFER_TOO_SMALL, FIELD_NOT_FOUND};
+use crate::host::field_helpers::type_codes;
+use crate::host::{Error, Result};
+
+/// A ledger object of any type, cached in a host slot.
+///
+/// Use this when the ledger entry type is not known ahead of time, or when no typed wrapper
+/// (such as `AccountRoot` or `Escrow`) exists for it yet.
+///
+/// There is no `ObjectSlot::iter_fields`: the host returns a cached object one field at a time
+/// and never as serialized bytes, so a slot cannot list its own fields. Use the free
+/// [`iter_fields`] on serialized bytes instead.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[repr(C)]
+pub struct ObjectSlot {
+    pub slot_num: i32,
+}
+
+impl ObjectSlot {
+    /// Caches the ledger object identified by `keylet` in the next free slot.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ObjectSlot)` - A handle to the cached object
+    /// * `Err(Error)` - If the object does not exist or no slot is available
+    pub fn load(keylet: &KeyletBytes) -> Result<ObjectSlot> {
+        let slot = unsafe { host::cache_ledger_obj(keylet.as_ptr(), keylet.len(), 0) };
+        if slot < 0 {
+            return Result::Err(Error::from_code(slot));
+        }
+        Result::Ok(ObjectSlot { slot_num: slot })
+    }
+
+    /// Reads a `UInt32` field, such as `sfield::Sequence`.
+    ///
+    /// Like the other typed getters, this returns `Error::InvalidField` without calling the host
+    /// if `field_code` is not of the expected serialized type, and `Error::FieldNotFound` if the
+    /// object does not have the field.
+    pub fn get_u32(&self, field_code: i32) -> Result<u32> {
+        self.get_typed(field_code, type_codes::UINT32)
+    }
+
+    /// Reads a `UInt64` field, such as `sfield::OwnerNode`.
+    pub fn get_u64(&self, field_code: i32) -> Result<u64> {
+        self.get_typed(field_code, type_codes::UINT64)
+    }
+
+    /// Reads an `AccountID` field, such as `sfield::Account`.
+    pub fn get_account(&self, field_code: i32) -> Result<AccountID> {
+        self.get_typed(field_code, type_codes::ACCOUNT_ID)
+    }
+
+    /// Reads an `Amount` field, such as `sfield::Balance`.
+    pub fn get_amount(&self, field_code: i32) -> Result<Amount> {
+        self.get_typed(field_code, type_codes::AMOUNT)
+    }
+
+    /// Reads a `Hash256` field, such as `sfield::PreviousTxnID`.
+    pub fn get_hash256(&self, field_code: i32) -> Result<Hash256> {
+        self.get_typed(field_code, type_codes::HASH256)
+    }
+
+    /// Reads a variable-length field, such as `sfield::Domain`, into a blob of capacity `N`.
+    pub fn get_blob<const N: usize>(&self, field_code: i32) -> Result<Blob<N>> {
+        self.get_typed(field_code, type_codes::BLOB)
+    }
+
+    /// Returns whether the cached object has the field `field_code`, without reading its value.
+    ///
+    /// The host only hands out objects one field at a time, so presence cannot be parsed once
+    /// up front. Instead this asks the host for the field with an empty buffer, which costs one
+    /// host call but copies nothing: a present field reports its size (or that the buffer is too
+    /// small), and an absent one reports `FIELD_NOT_FOUND`, which maps to `Ok(false)`. Any other
+    /// failure, such as an empty slot, is returned as an error rather than read as absence.
+    pub fn has_field(&self, field_code: i32) -> Result<bool> {
+        let mut empty = [0u8; 0];
+        let result_code =
+            unsafe { host::get_ledger_obj_field(self.slot_num, field_code, empty.as_mut_ptr(), 0) };
+        field_present(result_code)
+    }
+
+    /// Reads `field_code` after checking that it has the serialized type `expected_type`.
+    fn get_typed<T: super::FieldGetter>(&self, field_code: i32, expected_type: u16) -> Result<T> {
+        if field_code >> 16 != expected_type as i32 {
+            return Result::Err(Error::InvalidField);
+        }
+        ledger_object::get_field(self.slot_num, field_code)
+    }
+}
+
+impl LedgerObjectCommonFields for ObjectSlot {
+    fn get_slot_num(&self) -> i32 {
+        self.slot_num
+    }
+}
+
+/// Interprets the result code of a zero-length field read. See [`ObjectSlot::has_field`].
+fn field_present(result_code: i32) -> Result<bool> {
+    match result_code {
+        code if code >= 0 || code == BUFFER_TOO_SMALL => Result::Ok(true),
+        FIELD_NOT_FOUND => Result::Ok(false),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// Returns an iterator over the top-level fields of a serialized STObject.
+///
+/// Each item is `(sfield_code, value_bytes)`. For variable-length fields the length prefix is
+/// stripped; for nested objects and arrays the value is the inner serialization without its
+/// end marker.
+pub fn iter_fields(serialized: &[u8]) -> FieldIter<'_> {
+    FieldIter {
+        bytes: serialized,
+        pos: 0,
+    }
+}
+
+/// Iterator over the fields of a serialized STObject. See [`iter_fields`].
+///
+/// Fields are decoded the same way as by
+/// [`parse_transaction`](crate::core::serialize::parse_transaction). Iteration stops after the
+/// first malformed field, which is reported as `Error::InvalidDecoding`.
+#[derive(Debug, Clone)]
+pub struct FieldIter<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Iterator for FieldIter<'a> {
+    type Item = Result<(i32, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.bytes.len() {
+            return None;
+        }
+        match read_field(self.bytes, self.pos, 0) {
+            Result::Ok(field) if field.code != OBJECT_END_CODE && field.code != ARRAY_END_CODE => {
+                self.pos = field.end;
+                Some(Result::Ok((
+                    field.code,
+                    &self.bytes[field.value_start..field.value_end],
+                )))
+            }
+            _ => {
+                self.pos = self.bytes.len();
+                Some(Result::Err(Error::InvalidDecoding))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{EMPTY_SLOT, INVALID_FIELD, SLOT_OUT_RANGE};
+    use crate::sfield;
+
+    #[test]
+    fn test_typed_getters_check_field_type() {
+        let slot = ObjectSlot { slot_num: 1 };
+        // The test host reports every field as present.
+        assert!(slot.get_u32(sfield::Sequence).is_ok());
+        assert!(slot.get_u64(sfield::OwnerNode).is_ok());
+        assert!(slot.get_account(sfield::Account).is_ok());
+        assert!(slot.get_hash256(sfield::PreviousTxnID).is_ok());
+        assert!(slot.get_blob::<256>(sfield::Domain).is_ok());
+
+        assert_eq!(
+            slot.get_u32(sfield::Account).err().unwrap().code(),
+            INVALID_FIELD
+        );
+        assert_eq!(
+            slot.get_amount(sfield::Sequence).err().unwrap().code(),
+            INVALID_FIELD
+        );
+        assert_eq!(
+            slot.get_blob::<256>(sfield::Account).err().unwrap().code(),
+            INVALID_FIELD
+        );
+    }
+
+    #[test]
+    fn test_has_field() {
+        // The test host reports every field as present.
+        assert!(
+            ObjectSlot { slot_num: 1 }
+                .has_field(sfield::Domain)
+                .unwrap()
+        );
+
+        assert!(field_present(0).unwrap());
+        assert!(field_present(20).unwrap());
+        assert!(field_present(BUFFER_TOO_SMALL).unwrap());
+        assert!(!field_present(FIELD_NOT_FOUND).unwrap());
+        assert_eq!(field_present(EMPTY_SLOT).err().unwrap().code(), EMPTY_SLOT);
+        assert_eq!(
+            field_present(SLOT_OUT_RANGE).err().unwrap().code(),
+            SLOT_OUT_RANGE
+        );
+    }
+
+    /// A minimal serialized AccountRoot, in canonical field order.
+    const ACCOUNT_ROOT: &[u8] = &[
+        0x11, 0x00, 0x61, // LedgerEntryType = AccountRoot (0x0061)
+        0x22, 0x00, 0x00, 0x00, 0x00, // Flags = 0
+        0x24, 0x00, 0x00, 0x00, 0x05, // Sequence = 5
+        0x25, 0x00, 0x00, 0x01, 0x00, // PreviousTxnLgrSeq = 256
+        0x2D, 0x00, 0x00, 0x00, 0x02, // OwnerCount = 2
+        0x55, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        0xAA, 0xAA, 0xAA, // PreviousTxnID
+        0x62, 0x40, 0x00, 0x00, 0x00, 0x05, 0xF5, 0xE1, 0x00, // Balance = 100 XRP
+        0x00, 0x10, 0x10, 0x05, // TickSize = 5
+        0x81, 0x14, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D,
+        0x0E, 0x0F, 0x10, 0x11, 0x12, 0x13, 0x14, // Account
+    ];
+
+    #[test]
+    fn test_iter_fields_account_root() {
+        let mut iter = iter_fields(ACCOUNT_ROOT);
+        let mut next = || iter.next().unwrap().unwrap();
+
+        assert_eq!(next(), (sfield::LedgerEntryType, &[0x00, 0x61][..]));
+        assert_eq!(next(), (sfield::Flags, &[0u8; 4][..]));
+        assert_eq!(next(), (sfield::Sequence, &[0, 0, 0, 5][..]));
+        assert_eq!(next(), (sfield::PreviousTxnLgrSeq, &[0, 0, 1, 0][..]));
+        assert_eq!(next(), (sfield::OwnerCount, &[0, 0, 0, 2][..]));
+        assert_eq!(next(), (sfield::PreviousTxnID, &[0xAA; 32][..]));
+        assert_eq!(
+            next(),
+            (
+                sfield::Balance,
+                &[0x40, 0x00, 0x00, 0x00, 0x05, 0xF5, 0xE1, 0x00][..]
+            )
+        );
+        assert_eq!(next(), (sfield::TickSize, &[5][..]));
+
+        let (code, account) = next();
+        assert_eq!(code, sfield::Account);
+        assert_eq!(account.len(), 20);
+        assert_eq!(account[19], 0x14);
+
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_fields_nested_array() {
+        // Memos: [ Memo { MemoData: 0xBEEF } ]
+        let bytes = [
+            0xF9, 0xEA, 0x7D, 0x02, 0xBE, 0xEF, 0xE1, 0xF1, 0x24, 0, 0, 0, 1,
+        ];
+        let fields: [(i32, &[u8]); 2] = {
+            let mut iter = iter_fields(&bytes);
+            [iter.next().unwrap().unwrap(), iter.next().unwrap().unwrap()]
+        };
+
+        assert_eq!(fields[0].0, sfield::Memos);
+        assert_eq!(fields[0].1, &[0xEA, 0x7D, 0x02, 0xBE, 0xEF, 0xE1]);
+        assert_eq!(fields[1], (sfield::Sequence, &[0, 0, 0, 1][..]));
+
+        // The nested object can itself be iterated.
+        let mut memo = iter_fields(&fields[0].1[1..fields[0].1.len() - 1]);
+        assert_eq!(
+            memo.next().unwrap().unwrap(),
+            (sfield::MemoData, &[0xBE, 0xEF][..])
+        );
+    }
+
+    #[test]
+    fn test_iter_fields_truncated_input_errors_once() {
+        let mut iter = iter_fields(&ACCOUNT_ROOT[..ACCOUNT_ROOT.len() - 1]);
+        let mut errors = 0;
+        for field in iter.by_ref() {
+            if field.is_err() {
+                errors += 1;
+            }
+        }
+        assert_eq!(errors, 1);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_fields_issue_and_path_set() {
+        // Asset: an MPT issue (issuer, "no account", sequence), then Paths: one path of one
+        // account step.
+        let mut bytes = [0u8; 2 + 44 + 2 + 22];
+        bytes[..2].copy_from_slice(&[0x03, 0x18]);
+        bytes[2..22].copy_from_slice(&[0x11; 20]);
+        bytes[41] = 1;
+        bytes[42..46].copy_from_slice(&[0, 0, 0, 7]);
+        bytes[46..49].copy_from_slice(&[0x01, 0x12, 0x01]);
+        bytes[49..69].copy_from_slice(&[0x22; 20]);
+
+        let mut iter = iter_fields(&bytes);
+        let (code, issue) = iter.next().unwrap().unwrap();
+        assert_eq!((code, issue.len()), (sfield::Asset, 44));
+        let (code, paths) = iter.next().unwrap().unwrap();
+        assert_eq!((code, paths.len()), (sfield::Paths, 22));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_fields_rejects_stray_end_marker() {
+        let mut iter = iter_fields(&[0x24, 0, 0, 0, 1, 0xE1]);
+        assert!(iter.next().unwrap().is_ok());
+        assert!(iter.next().unwrap().is_err());
+        assert!(iter.next().is_none());
+    }
+}
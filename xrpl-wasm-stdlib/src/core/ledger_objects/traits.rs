@@ -1,3 +1,4 @@
+use crate::core::ledger::time::{RippleTime, now};
 use crate::core::ledger_objects::{current_ledger_object, ledger_object};
 use crate::core::types::account_id::AccountID;
 use crate::core::types::amount::Amount;
@@ -107,6 +108,9 @@ pub trait CurrentEscrowFields: CurrentLedgerObjectCommonFields {
     }
 
     /// The amount currently held in the escrow (could be XRP, IOU, or MPT).
+    ///
+    /// This is read from the escrow object, not the `EscrowFinish` transaction, which has no
+    /// `Amount` field.
     fn get_amount(&self) -> Result<Amount> {
         current_ledger_object::get_field(sfield::Amount)
     }
@@ -177,6 +181,13 @@ pub trait CurrentEscrowFields: CurrentLedgerObjectCommonFields {
         current_ledger_object::get_field(sfield::PreviousTxnLgrSeq)
     }
 
+    /// The sequence number of the EscrowCreate transaction that created this escrow. Together
+    /// with the owner, this identifies the escrow's keylet. Returns `Error::FieldNotFound` on
+    /// escrows that do not record it.
+    fn get_sequence(&self) -> Result<u32> {
+        current_ledger_object::get_field(sfield::Sequence)
+    }
+
     /// An arbitrary tag to further specify the source for this escrow, such as a hosted recipient
     /// at the owner's address.
     fn get_source_tag(&self) -> Result<Option<u32>> {
@@ -511,3 +522,195 @@ pub trait AccountFields: LedgerObjectCommonFields {
         ledger_object::get_field_optional(self.get_slot_num(), sfield::WalletSize)
     }
 }
+
+/// Trait providing access to fields specific to Offer objects in any ledger.
+///
+/// This trait extends `LedgerObjectCommonFields` and provides methods to access fields that are
+/// specific to Offer objects (open orders in the decentralized exchange).
+pub trait OfferFields: LedgerObjectCommonFields {
+    /// The address of the account that placed this offer.
+    fn get_account(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Account)
+    }
+
+    /// The sequence number (or ticket number) of the OfferCreate transaction that placed this
+    /// offer. Together with the account, this identifies the offer's keylet (see
+    /// [`offer_keylet`](crate::core::types::keylets::offer_keylet)).
+    fn get_sequence(&self) -> Result<u32> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Sequence)
+    }
+
+    /// The time after which this offer is considered unfunded and can no longer be consumed.
+    /// Omitted if the offer never expires.
+    fn get_expiration(&self) -> Result<Option<RippleTime>> {
+        match ledger_object::get_field_optional::<u32>(self.get_slot_num(), sfield::Expiration) {
+            Ok(expiration) => Ok(expiration.map(RippleTime::from_seconds)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns whether this offer has expired as of `now`.
+    ///
+    /// Pass the parent ledger's close time (see [`now`]); an offer is expired once that time is
+    /// greater than or equal to its expiration. An offer without an expiration never expires.
+    fn is_expired(&self, now: RippleTime) -> Result<bool> {
+        match self.get_expiration() {
+            Ok(Some(expiration)) => Ok(expiration.is_expired_at(now)),
+            Ok(None) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The ID of the order book directory page that links to this offer.
+    ///
+    /// The first 24 bytes identify the order book (the pair of assets being exchanged) and the
+    /// last 8 bytes encode the offer's quality (exchange rate), so offers in the same book sort
+    /// by this value from best to worst rate.
+    fn get_book_directory(&self) -> Result<Hash256> {
+        ledger_object::get_field(self.get_slot_num(), sfield::BookDirectory)
+    }
+
+    /// A hint indicating which page of the order book directory links to this offer, in case
+    /// the directory consists of multiple pages.
+    fn get_book_node(&self) -> Result<u64> {
+        ledger_object::get_field(self.get_slot_num(), sfield::BookNode)
+    }
+}
+
+/// Trait providing access to fields specific to Check objects in any ledger.
+///
+/// This trait extends `LedgerObjectCommonFields` and provides methods to access fields that are
+/// specific to Check objects (deferred payments that the destination can cash).
+pub trait CheckFields: LedgerObjectCommonFields {
+    /// The sender of the check. Cashing the check debits this address's balance.
+    fn get_account(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Account)
+    }
+
+    /// The intended recipient of the check. Only this address can cash the check.
+    fn get_destination(&self) -> Result<AccountID> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Destination)
+    }
+
+    /// The maximum amount of currency this check can debit the sender.
+    fn get_send_max(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::SendMax)
+    }
+
+    /// The time after which this check can no longer be cashed. Omitted if the check never
+    /// expires.
+    fn get_expiration(&self) -> Result<Option<RippleTime>> {
+        match ledger_object::get_field_optional::<u32>(self.get_slot_num(), sfield::Expiration) {
+            Ok(expiration) => Ok(expiration.map(RippleTime::from_seconds)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns whether this check has expired, judged against the parent ledger's close time.
+    ///
+    /// A check is expired once the parent close time is greater than or equal to its
+    /// expiration, matching rippled's `CheckCash`. A check without an expiration never expires.
+    fn is_expired(&self) -> Result<bool> {
+        let expiration = match self.get_expiration() {
+            Ok(Some(expiration)) => expiration,
+            Ok(None) => return Ok(false),
+            Err(e) => return Err(e),
+        };
+        match now() {
+            Ok(close_time) => Ok(expiration.is_expired_at(close_time)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// One of the two accounts of a trust line (`RippleState`).
+///
+/// The account with the numerically lower AccountID is always the low side; its settings live
+/// in the `Low*` fields and the other account's in the `High*` fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustLineSide {
+    /// The account with the lower AccountID.
+    Low,
+    /// The account with the higher AccountID.
+    High,
+}
+
+impl TrustLineSide {
+    /// Returns the side `account` occupies on its trust line with `peer`.
+    pub fn of(account: &AccountID, peer: &AccountID) -> TrustLineSide {
+        if account < peer {
+            TrustLineSide::Low
+        } else {
+            TrustLineSide::High
+        }
+    }
+}
+
+/// The quality (parts per billion) rippled treats as 1:1.
+pub const QUALITY_ONE: u32 = 1_000_000_000;
+
+/// Trait providing access to fields specific to RippleState (trust line) objects in any ledger.
+///
+/// # Quality
+///
+/// Each side of a trust line may set a quality for amounts moving across it, as a rate in parts
+/// per billion: [`QUALITY_ONE`] (`1_000_000_000`) is 1:1.
+///
+/// - **Quality in**: Balances the account receives on this line are valued at
+///   `amount × quality_in / 1_000_000_000`.
+/// - **Quality out**: Balances the account sends on this line are valued at
+///   `amount × quality_out / 1_000_000_000`.
+///
+/// The ledger omits a quality that is 1:1 (and treats a stored `0` the same way), so the
+/// getters return `None` for the default and `Some(rate)` only for a non-default rate.
+pub trait RippleStateFields: LedgerObjectCommonFields {
+    /// The balance of the trust line from the low account's perspective. A negative balance
+    /// means the low account owes the high account. The issuer is a neutral placeholder.
+    fn get_balance(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::Balance)
+    }
+
+    /// The limit the low account has set on the trust line, issued by the low account.
+    fn get_low_limit(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::LowLimit)
+    }
+
+    /// The limit the high account has set on the trust line, issued by the high account.
+    fn get_high_limit(&self) -> Result<Amount> {
+        ledger_object::get_field(self.get_slot_num(), sfield::HighLimit)
+    }
+
+    /// The quality in set by `side`, or `None` if it is the default 1:1. See the trait
+    /// documentation for the encoding.
+    fn get_quality_in(&self, side: TrustLineSide) -> Result<Option<u32>> {
+        let field = match side {
+            TrustLineSide::Low => sfield::LowQualityIn,
+            TrustLineSide::High => sfield::HighQualityIn,
+        };
+        match ledger_object::get_field_optional(self.get_slot_num(), field) {
+            Ok(quality) => Ok(non_default_quality(quality)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The quality out set by `side`, or `None` if it is the default 1:1. See the trait
+    /// documentation for the encoding.
+    fn get_quality_out(&self, side: TrustLineSide) -> Result<Option<u32>> {
+        let field = match side {
+            TrustLineSide::Low => sfield::LowQualityOut,
+            TrustLineSide::High => sfield::HighQualityOut,
+        };
+        match ledger_object::get_field_optional(self.get_slot_num(), field) {
+            Ok(quality) => Ok(non_default_quality(quality)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Maps a stored quality to `None` when it means 1:1 (absent, `0` or [`QUALITY_ONE`]).
+pub(crate) fn non_default_quality(quality: Option<u32>) -> Option<u32> {
+    match quality {
+        Some(0) | Some(QUALITY_ONE) | None => None,
+        quality => quality,
+    }
+}
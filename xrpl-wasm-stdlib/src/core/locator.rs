@@ -14,6 +14,7 @@
 //! # let _ = (l.len() >= 3);
 //! ```
 
+use crate::host::{Error, Result};
 use core::mem::MaybeUninit;
 
 /// The size of the buffer, in bytes, to use for any new locator
@@ -107,6 +108,18 @@ impl Locator {
         self.cur_buffer_index == 0
     }
 
+    /// Returns the most recently packed sfield code or array index, if anything has been packed.
+    pub fn last(&self) -> Option<i32> {
+        // A slot-prefixed locator starts with one extra byte that is not part of a packed value.
+        let first_packed = self.cur_buffer_index % 4;
+        if self.cur_buffer_index < first_packed + 4 {
+            return None;
+        }
+        let mut value_bytes = [0u8; 4];
+        value_bytes.copy_from_slice(&self.buffer[self.cur_buffer_index - 4..self.cur_buffer_index]);
+        Some(i32::from_le_bytes(value_bytes))
+    }
+
     pub fn repack_last(&mut self, sfield_or_index: i32) -> bool {
         self.cur_buffer_index -= 4;
 
@@ -116,4 +129,93 @@ impl Locator {
 
         true
     }
+
+    /// Returns a copy of this locator with `sfield_or_index` packed onto the end, leaving `self`
+    /// untouched.
+    ///
+    /// This suits deriving several paths from one base, such as one locator per array index.
+    /// Returns `Error::LocatorMalformed` if the locator is already full.
+    pub fn with(&self, sfield_or_index: i32) -> Result<Locator> {
+        let mut locator = self.clone();
+        if locator.pack(sfield_or_index) {
+            Result::Ok(locator)
+        } else {
+            Result::Err(Error::LocatorMalformed)
+        }
+    }
+
+    /// Replaces the most recently packed value with `index`, typically to step an array index
+    /// in place.
+    ///
+    /// Returns `Error::LocatorMalformed` if nothing has been packed yet.
+    pub fn set_last_index(&mut self, index: i32) -> Result<()> {
+        if self.last().is_none() {
+            return Result::Err(Error::LocatorMalformed);
+        }
+        self.repack_last(index);
+        Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sfield;
+
+    #[test]
+    fn test_last_returns_most_recent_value() {
+        let mut locator = Locator::new();
+        assert_eq!(locator.last(), None);
+        locator.pack(sfield::Memos);
+        locator.pack(2);
+        assert_eq!(locator.last(), Some(2));
+        locator.repack_last(sfield::MemoType);
+        assert_eq!(locator.last(), Some(sfield::MemoType));
+    }
+
+    #[test]
+    fn test_last_skips_slot_byte() {
+        let mut locator = Locator::new_with_slot(3);
+        assert_eq!(locator.last(), None);
+        locator.pack(sfield::Account);
+        assert_eq!(locator.last(), Some(sfield::Account));
+    }
+
+    #[test]
+    fn test_with_leaves_base_untouched() {
+        let mut base = Locator::new();
+        base.pack(sfield::Memos);
+        let first = base.with(0).unwrap();
+        let second = base.with(1).unwrap().with(sfield::MemoType).unwrap();
+
+        assert_eq!(base.len(), 4);
+        assert_eq!(first.last(), Some(0));
+        assert_eq!(second.len(), 12);
+        assert_eq!(second.last(), Some(sfield::MemoType));
+    }
+
+    #[test]
+    fn test_with_errors_when_full() {
+        use crate::host::error_codes::LOCATOR_MALFORMED;
+
+        let mut locator = Locator::new();
+        while locator.pack(0) {}
+        assert_eq!(locator.with(0).err().unwrap().code(), LOCATOR_MALFORMED);
+    }
+
+    #[test]
+    fn test_set_last_index() {
+        use crate::host::error_codes::LOCATOR_MALFORMED;
+
+        let mut locator = Locator::new();
+        assert_eq!(
+            locator.set_last_index(1).err().unwrap().code(),
+            LOCATOR_MALFORMED
+        );
+        locator.pack(sfield::Memos);
+        locator.pack(0);
+        locator.set_last_index(3).unwrap();
+        assert_eq!(locator.last(), Some(3));
+        assert_eq!(locator.len(), 8);
+    }
 }
@@ -1,17 +1,25 @@
 //! Core modules for XRPL transaction and ledger access.
 //!
 //! This namespace provides typed accessors and utilities used by smart contracts:
+//! - [`budget`]: Bound long-running loops within the computation budget
 //! - [`current_tx`]: Read fields from the current transaction
+//! - [`ledger`]: Read ledger-wide values such as fee settings
 //! - [`ledger_objects`]: Read fields from on-ledger objects (current or cached)
 //! - [`types`]: Strongly-typed XRPL primitives (AccountID, Hash256, Amount, etc.)
 //! - [`locator`]: Build locators for nested field access
+//! - [`serialize`]: Assemble objects in the canonical binary format
+//! - [`state`]: Persist small pieces of state in the current escrow's `Data` field
 //! - [`constants`]: Internal helpers for buffer sizes
 //!
 //! Start with [`current_tx::escrow_finish::EscrowFinish`] to access EscrowFinish TX fields,
 //! or [`ledger_objects::current_escrow::get_current_escrow`] to access the active escrow.
 
+pub mod budget;
 pub mod constants;
 pub mod current_tx;
+pub mod ledger;
 pub mod ledger_objects;
 pub mod locator;
+pub mod serialize;
+pub mod state;
 pub mod types;
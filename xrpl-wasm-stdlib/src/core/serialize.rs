@@ -0,0 +1,878 @@
+//! # Binary Serialization
+//!
+//! Helpers for assembling objects in the XRPL canonical binary format, for example to build the
+//! preimage of a hash or an object to hand back to the host.
+//!
+//! [`ObjectBuilder`] appends `(field header, value)` pairs into a fixed-size buffer. Values are
+//! encoded through the [`Serialize`] trait. Fields must be added in canonical order (ascending
+//! type code, then ascending field code); the builder does not reorder them.
+//!
+//! [`parse_transaction`] goes the other way for a self-contained transaction blob, such as one
+//! received in a memo: it checks the layout locally and exposes the transaction's fields and
+//! signing hash. Field decoding lives here for the whole crate; [`iter_fields`] walks objects
+//! with the same decoder.
+//!
+//! [`iter_fields`]: crate::core::ledger_objects::slot::iter_fields
+//!
+//! ## Example
+//!
+//! ```
+//! use xrpl_wasm_stdlib::core::serialize::ObjectBuilder;
+//! use xrpl_wasm_stdlib::core::types::amount::Amount;
+//! use xrpl_wasm_stdlib::sfield;
+//!
+//! let mut builder: ObjectBuilder = ObjectBuilder::new();
+//! builder.add(sfield::TransactionType, &0u16).unwrap_or_panic();
+//! builder
+//!     .add_amount(sfield::Amount, &Amount::XRP { num_drops: 1_000_000 })
+//!     .unwrap_or_panic();
+//! assert_eq!(builder.len(), 3 + 9);
+//! ```
+
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::amount::Amount;
+use crate::core::types::blob::{Blob, DEFAULT_BLOB_SIZE};
+use crate::core::types::keylets::sha512_half;
+use crate::core::types::transaction_type::TransactionType;
+use crate::core::types::uint::{Hash256, UInt};
+use crate::host::{Error, Result};
+use crate::sfield;
+
+/// Default capacity, in bytes, of an [`ObjectBuilder`].
+pub const DEFAULT_OBJECT_SIZE: usize = DEFAULT_BLOB_SIZE;
+
+/// Marks the end of an inner object (the `ObjectEndMarker` field header).
+pub const OBJECT_END_MARKER: u8 = 0xE1;
+
+/// Marks the end of an array (the `ArrayEndMarker` field header).
+pub const ARRAY_END_MARKER: u8 = 0xF1;
+
+/// Types that can be written in the XRPL canonical binary format.
+///
+/// Implementations write only the value (including any length prefix the type requires), never
+/// the field header.
+pub trait Serialize {
+    /// Appends the canonical encoding of `self` to `out`.
+    ///
+    /// Returns `Error::BufferTooSmall` if `out` does not have enough remaining capacity.
+    fn serialize<const N: usize>(&self, out: &mut Blob<N>) -> Result<()>;
+}
+
+impl Serialize for u8 {
+    fn serialize<const N: usize>(&self, out: &mut Blob<N>) -> Result<()> {
+        out.write_at(out.len(), &self.to_be_bytes())
+    }
+}
+
+impl Serialize for u16 {
+    fn serialize<const N: usize>(&self, out: &mut Blob<N>) -> Result<()> {
+        out.write_at(out.len(), &self.to_be_bytes())
+    }
+}
+
+impl Serialize for u32 {
+    fn serialize<const N: usize>(&self, out: &mut Blob<N>) -> Result<()> {
+        out.write_at(out.len(), &self.to_be_bytes())
+    }
+}
+
+impl Serialize for u64 {
+    fn serialize<const N: usize>(&self, out: &mut Blob<N>) -> Result<()> {
+        out.write_at(out.len(), &self.to_be_bytes())
+    }
+}
+
+impl<const M: usize> Serialize for UInt<M> {
+    fn serialize<const N: usize>(&self, out: &mut Blob<N>) -> Result<()> {
+        out.write_at(out.len(), &self.0)
+    }
+}
+
+/// Account IDs are variable-length encoded, so they carry a one-byte length prefix.
+impl Serialize for AccountID {
+    fn serialize<const N: usize>(&self, out: &mut Blob<N>) -> Result<()> {
+        match out.write_at(out.len(), &[ACCOUNT_ID_SIZE as u8]) {
+            Result::Ok(()) => out.write_at(out.len(), &self.0),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+}
+
+/// Amounts are written as 8 (XRP), 33 (MPT) or 48 (IOU) bytes. Issued amounts must already be
+/// canonical (see [`Amount::is_canonical`]); anything else returns `Error::InvalidFloatInput`,
+/// since the host would reject it. Use [`Amount::canonicalize`] to normalize first.
+impl Serialize for Amount {
+    fn serialize<const N: usize>(&self, out: &mut Blob<N>) -> Result<()> {
+        if !self.is_canonical() {
+            return Result::Err(Error::InvalidFloatInput);
+        }
+        match self.to_serialized_bytes() {
+            Result::Ok((bytes, len)) => out.write_at(out.len(), &bytes[..len]),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+}
+
+/// Builds a serialized STObject field by field into a fixed-size buffer.
+///
+/// # Type Parameters
+///
+/// * `N` - The capacity of the underlying buffer in bytes (defaults to [`DEFAULT_OBJECT_SIZE`])
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectBuilder<const N: usize = DEFAULT_OBJECT_SIZE> {
+    buffer: Blob<N>,
+}
+
+impl<const N: usize> Default for ObjectBuilder<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ObjectBuilder<N> {
+    /// Creates an empty builder.
+    pub const fn new() -> Self {
+        Self {
+            buffer: Blob::new(),
+        }
+    }
+
+    /// Appends a field header for `field_code` followed by the encoding of `value`.
+    ///
+    /// On error the builder may contain a partially written field and should be discarded.
+    pub fn add<T: Serialize>(&mut self, field_code: i32, value: &T) -> Result<()> {
+        match self.add_field_header(field_code) {
+            Result::Ok(()) => value.serialize(&mut self.buffer),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Appends an `Amount` field in its canonical 8, 33 or 48-byte encoding.
+    #[inline]
+    pub fn add_amount(&mut self, field_code: i32, amount: &Amount) -> Result<()> {
+        self.add(field_code, amount)
+    }
+
+    /// Appends `object` as an inner-object field: the header for `field_code`, the object's
+    /// fields, then [`OBJECT_END_MARKER`].
+    pub fn add_object<const M: usize>(
+        &mut self,
+        field_code: i32,
+        object: &ObjectBuilder<M>,
+    ) -> Result<()> {
+        match self.add_field_header(field_code) {
+            Result::Ok(()) => {}
+            Result::Err(e) => return Result::Err(e),
+        }
+        match self.buffer.write_at(self.buffer.len(), object.as_slice()) {
+            Result::Ok(()) => self
+                .buffer
+                .write_at(self.buffer.len(), &[OBJECT_END_MARKER]),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Appends an array-of-objects field: the header for `field_code`, each of `items` as an
+    /// inner object named `item_field_code` (see [`ObjectBuilder::add_object`]), then
+    /// [`ARRAY_END_MARKER`].
+    ///
+    /// Every element of an XRPL array is a named object, such as each `SignerEntry` in
+    /// `SignerEntries` or each `Memo` in `Memos`, so the element's field is passed separately.
+    pub fn add_array<const M: usize>(
+        &mut self,
+        field_code: i32,
+        item_field_code: i32,
+        items: &[ObjectBuilder<M>],
+    ) -> Result<()> {
+        match self.add_field_header(field_code) {
+            Result::Ok(()) => {}
+            Result::Err(e) => return Result::Err(e),
+        }
+        for item in items {
+            match self.add_object(item_field_code, item) {
+                Result::Ok(()) => {}
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+        self.buffer.write_at(self.buffer.len(), &[ARRAY_END_MARKER])
+    }
+
+    /// Returns the serialized bytes written so far.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+
+    /// Returns the number of serialized bytes written so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns true if no fields have been added.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Appends the 1 to 3-byte field header for `field_code`.
+    fn add_field_header(&mut self, field_code: i32) -> Result<()> {
+        let (header, len) = encode_field_header(field_code);
+        self.buffer.write_at(self.buffer.len(), &header[..len])
+    }
+}
+
+/// Encodes the field header for `field_code` (`type_code << 16 | field_code`), returning the
+/// header bytes and how many of them are used.
+pub(crate) fn encode_field_header(field_code: i32) -> ([u8; 3], usize) {
+    let type_code = ((field_code >> 16) & 0xFF) as u8;
+    let field = (field_code & 0xFF) as u8;
+    match (type_code < 16, field < 16) {
+        (true, true) => ([type_code << 4 | field, 0, 0], 1),
+        (true, false) => ([type_code << 4, field, 0], 2),
+        (false, true) => ([field, type_code, 0], 2),
+        (false, false) => ([0, type_code, field], 3),
+    }
+}
+
+/// Largest serialized transaction, in bytes, accepted by [`parse_transaction`].
+pub const MAX_PARSED_TX_SIZE: usize = DEFAULT_OBJECT_SIZE;
+
+/// Prefix of the data hashed to produce a transaction's single-signing hash (`"STX\0"`).
+pub const TX_SIGN_PREFIX: [u8; 4] = *b"STX\0";
+
+/// How deeply inner objects and arrays may nest before a blob is rejected.
+const MAX_NESTING_DEPTH: usize = 16;
+
+/// A serialized transaction, checked by [`parse_transaction`], whose fields can be read without
+/// calling the host.
+///
+/// Only top-level fields are addressable; inner objects and arrays are returned as their raw
+/// contents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParsedTx<'a> {
+    bytes: &'a [u8],
+}
+
+/// One field located in a serialized object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RawField {
+    /// The field code (`type_code << 16 | field_code`).
+    pub(crate) code: i32,
+    /// Offset of the field header.
+    pub(crate) start: usize,
+    /// Offsets of the value, excluding any length prefix or end marker.
+    pub(crate) value_start: usize,
+    pub(crate) value_end: usize,
+    /// Offset just past the field, including any end marker.
+    pub(crate) end: usize,
+}
+
+/// Checks that `bytes` is a well-formed serialized transaction (an STObject carrying a
+/// `TransactionType`, such as one received in a memo) and wraps it for field access.
+///
+/// This is a local parser: nothing is sent to the host, and nothing beyond the binary layout is
+/// validated. In particular the signature is not checked; use [`ParsedTx::signing_hash`] together
+/// with the signing public key for that.
+///
+/// # Errors
+///
+/// * `Error::DataFieldTooLarge` - `bytes` is longer than [`MAX_PARSED_TX_SIZE`]
+/// * `Error::InvalidDecoding` - `bytes` is truncated, contains an unknown field type or an
+///   unbalanced end marker, or nests deeper than the parser allows
+/// * `Error::FieldNotFound` - there is no top-level `TransactionType` field
+pub fn parse_transaction(bytes: &[u8]) -> Result<ParsedTx<'_>> {
+    if bytes.len() > MAX_PARSED_TX_SIZE {
+        return Result::Err(Error::DataFieldTooLarge);
+    }
+    let mut pos = 0;
+    while pos < bytes.len() {
+        match read_field(bytes, pos, 0) {
+            Result::Ok(field) if field.code == OBJECT_END_CODE || field.code == ARRAY_END_CODE => {
+                return Result::Err(Error::InvalidDecoding);
+            }
+            Result::Ok(field) => pos = field.end,
+            Result::Err(e) => return Result::Err(e),
+        }
+    }
+    let tx = ParsedTx { bytes };
+    match tx.tx_type() {
+        Result::Ok(_) => Result::Ok(tx),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+impl<'a> ParsedTx<'a> {
+    /// Returns the serialized transaction.
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the value of the top-level field `field_code`, without its length prefix (for
+    /// variable-length fields) or end marker (for inner objects and arrays).
+    ///
+    /// Returns `Error::FieldNotFound` if the transaction does not contain the field.
+    pub fn get_field(&self, field_code: i32) -> Result<&'a [u8]> {
+        let mut pos = 0;
+        while pos < self.bytes.len() {
+            match read_field(self.bytes, pos, 0) {
+                Result::Ok(field) if field.code == field_code => {
+                    return Result::Ok(&self.bytes[field.value_start..field.value_end]);
+                }
+                Result::Ok(field) => pos = field.end,
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+        Result::Err(Error::FieldNotFound)
+    }
+
+    /// Returns the transaction's `TransactionType`.
+    pub fn tx_type(&self) -> Result<TransactionType> {
+        match self.get_field(sfield::TransactionType) {
+            Result::Ok(&[hi, lo]) => {
+                Result::Ok(TransactionType::from(i16::from_be_bytes([hi, lo])))
+            }
+            Result::Ok(_) => Result::Err(Error::InvalidDecoding),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Returns the transaction's sending `Account`.
+    pub fn account(&self) -> Result<AccountID> {
+        match self.get_field(sfield::Account) {
+            Result::Ok(value) if value.len() == ACCOUNT_ID_SIZE => {
+                let mut account = [0u8; ACCOUNT_ID_SIZE];
+                account.copy_from_slice(value);
+                Result::Ok(AccountID(account))
+            }
+            Result::Ok(_) => Result::Err(Error::InvalidDecoding),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Returns the single-signing hash of the transaction: the SHA-512Half, computed by the host,
+    /// of [`TX_SIGN_PREFIX`] followed by every field except `TxnSignature` and `Signers`.
+    ///
+    /// This is the message a single signer signs with the key in `SigningPubKey`. Multi-signed
+    /// transactions hash each signer's account in as well, which this does not do.
+    pub fn signing_hash(&self) -> Result<Hash256> {
+        let mut data: Blob<{ TX_SIGN_PREFIX.len() + MAX_PARSED_TX_SIZE }> = Blob::new();
+        match self.write_signing_data(&mut data) {
+            Result::Ok(()) => {}
+            Result::Err(e) => return Result::Err(e),
+        }
+        match sha512_half(data.as_slice()) {
+            Result::Ok(hash) => Result::Ok(Hash256::from(hash)),
+            Result::Err(e) => Result::Err(e),
+        }
+    }
+
+    /// Appends the data hashed by [`ParsedTx::signing_hash`] to `out`.
+    fn write_signing_data<const N: usize>(&self, out: &mut Blob<N>) -> Result<()> {
+        match out.write_at(out.len(), &TX_SIGN_PREFIX) {
+            Result::Ok(()) => {}
+            Result::Err(e) => return Result::Err(e),
+        }
+        let mut pos = 0;
+        while pos < self.bytes.len() {
+            let field = match read_field(self.bytes, pos, 0) {
+                Result::Ok(field) => field,
+                Result::Err(e) => return Result::Err(e),
+            };
+            if field.code != sfield::TxnSignature && field.code != sfield::Signers {
+                match out.write_at(out.len(), &self.bytes[field.start..field.end]) {
+                    Result::Ok(()) => {}
+                    Result::Err(e) => return Result::Err(e),
+                }
+            }
+            pos = field.end;
+        }
+        Result::Ok(())
+    }
+}
+
+/// Field code of the `ObjectEndMarker` (type 14, field 1).
+pub(crate) const OBJECT_END_CODE: i32 = (14 << 16) | 1;
+
+/// Field code of the `ArrayEndMarker` (type 15, field 1).
+pub(crate) const ARRAY_END_CODE: i32 = (15 << 16) | 1;
+
+/// Reads the field whose header starts at `pos`, at nesting level `depth`.
+///
+/// An end marker is returned as a field of its own (with code [`OBJECT_END_CODE`] or
+/// [`ARRAY_END_CODE`]); callers walking a top-level object should treat one as malformed.
+pub(crate) fn read_field(bytes: &[u8], pos: usize, depth: usize) -> Result<RawField> {
+    let (type_code, field, value_start) = match decode_field_header(bytes, pos) {
+        Some(header) => header,
+        None => return Result::Err(Error::InvalidDecoding),
+    };
+    let code = ((type_code as i32) << 16) | field as i32;
+    if code == OBJECT_END_CODE || code == ARRAY_END_CODE {
+        return Result::Ok(RawField {
+            code,
+            start: pos,
+            value_start,
+            value_end: value_start,
+            end: value_start,
+        });
+    }
+    let (value_start, value_end, end) = match type_code {
+        14 | 15 => {
+            if depth >= MAX_NESTING_DEPTH {
+                return Result::Err(Error::InvalidDecoding);
+            }
+            let terminator = if type_code == 14 {
+                OBJECT_END_CODE
+            } else {
+                ARRAY_END_CODE
+            };
+            let mut cursor = value_start;
+            loop {
+                let inner = match read_field(bytes, cursor, depth + 1) {
+                    Result::Ok(inner) => inner,
+                    Result::Err(e) => return Result::Err(e),
+                };
+                if inner.code == terminator {
+                    break (value_start, cursor, inner.end);
+                }
+                // Arrays hold only inner objects, and only objects end with an object marker.
+                if (type_code == 15 && inner.code >> 16 != 14)
+                    || inner.code == OBJECT_END_CODE
+                    || inner.code == ARRAY_END_CODE
+                {
+                    return Result::Err(Error::InvalidDecoding);
+                }
+                cursor = inner.end;
+            }
+        }
+        7 | 8 | 19 => match decode_vl_length(bytes, value_start) {
+            Some((len, start)) => (start, start + len, start + len),
+            None => return Result::Err(Error::InvalidDecoding),
+        },
+        _ => match fixed_value_len(type_code, bytes, value_start) {
+            Some(len) => (value_start, value_start + len, value_start + len),
+            None => return Result::Err(Error::InvalidDecoding),
+        },
+    };
+    if end > bytes.len() {
+        return Result::Err(Error::InvalidDecoding);
+    }
+    Result::Ok(RawField {
+        code,
+        start: pos,
+        value_start,
+        value_end,
+        end,
+    })
+}
+
+/// Decodes the 1 to 3-byte field header at `pos` into `(type_code, field_code, value_offset)`;
+/// the inverse of [`encode_field_header`].
+fn decode_field_header(bytes: &[u8], pos: usize) -> Option<(u8, u8, usize)> {
+    let first = *bytes.get(pos)?;
+    match (first >> 4, first & 0x0F) {
+        (0, 0) => Some((*bytes.get(pos + 1)?, *bytes.get(pos + 2)?, pos + 3)),
+        (0, field) => Some((*bytes.get(pos + 1)?, field, pos + 2)),
+        (type_code, 0) => Some((type_code, *bytes.get(pos + 1)?, pos + 2)),
+        (type_code, field) => Some((type_code, field, pos + 1)),
+    }
+}
+
+/// Decodes the 1 to 3-byte length prefix at `pos` into `(length, content_offset)`.
+fn decode_vl_length(bytes: &[u8], pos: usize) -> Option<(usize, usize)> {
+    let b0 = *bytes.get(pos)? as usize;
+    match b0 {
+        0..=192 => Some((b0, pos + 1)),
+        193..=240 => {
+            let b1 = *bytes.get(pos + 1)? as usize;
+            Some((193 + ((b0 - 193) << 8) + b1, pos + 2))
+        }
+        241..=254 => {
+            let b1 = *bytes.get(pos + 1)? as usize;
+            let b2 = *bytes.get(pos + 2)? as usize;
+            Some((12481 + ((b0 - 241) << 16) + (b1 << 8) + b2, pos + 3))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the length of a value of `type_code` starting at `pos`, for types that are not
+/// length-prefixed, objects or arrays.
+fn fixed_value_len(type_code: u8, bytes: &[u8], pos: usize) -> Option<usize> {
+    match type_code {
+        1 => Some(2),      // UInt16
+        2 | 10 => Some(4), // UInt32, Int32
+        3 | 11 => Some(8), // UInt64, Int64
+        4 => Some(16),     // Hash128
+        5 => Some(32),     // Hash256
+        6 => {
+            // Amount: issued (48), MPT (33) or XRP (8)
+            let first = *bytes.get(pos)?;
+            match (first & 0x80 != 0, first & 0x20 != 0) {
+                (true, _) => Some(48),
+                (false, true) => Some(33),
+                (false, false) => Some(8),
+            }
+        }
+        9 | 20 => Some(12),  // Number, UInt96
+        16 => Some(1),       // UInt8
+        17 | 26 => Some(20), // Hash160, Currency
+        18 => path_set_len(bytes, pos),
+        21 => Some(24), // Hash192
+        22 => Some(48), // UInt384
+        23 => Some(64), // UInt512
+        24 => issue_len(bytes, pos),
+        25 => {
+            // XChainBridge: locking door, locking issue, issuing door, issuing issue
+            let mut cursor = pos;
+            for _ in 0..2 {
+                let (len, start) = decode_vl_length(bytes, cursor)?;
+                cursor = start + len;
+                cursor += issue_len(bytes, cursor)?;
+            }
+            Some(cursor - pos)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the length of the Issue at `pos`: a currency (20 bytes, XRP if all zero), then an
+/// issuer (20 bytes) unless the currency is XRP. An MPT issue stores the issuer, then the
+/// all-but-one zero "no account", then a 4-byte sequence.
+fn issue_len(bytes: &[u8], pos: usize) -> Option<usize> {
+    const NO_ACCOUNT: [u8; ACCOUNT_ID_SIZE] = {
+        let mut account = [0u8; ACCOUNT_ID_SIZE];
+        account[ACCOUNT_ID_SIZE - 1] = 1;
+        account
+    };
+    let currency = bytes.get(pos..pos + 20)?;
+    if currency.iter().all(|b| *b == 0) {
+        return Some(20);
+    }
+    match bytes.get(pos + 20..pos + 40)? == NO_ACCOUNT {
+        true => Some(44),
+        false => Some(40),
+    }
+}
+
+/// Returns the length of the PathSet at `pos`, including its terminating zero byte.
+fn path_set_len(bytes: &[u8], pos: usize) -> Option<usize> {
+    const PATH_END: u8 = 0x00;
+    const PATH_BOUNDARY: u8 = 0xFF;
+    const STEP_ACCOUNT: u8 = 0x01;
+    const STEP_CURRENCY: u8 = 0x10;
+    const STEP_ISSUER: u8 = 0x20;
+
+    let mut cursor = pos;
+    loop {
+        let step = *bytes.get(cursor)?;
+        cursor += 1;
+        match step {
+            PATH_END => return Some(cursor - pos),
+            PATH_BOUNDARY => {}
+            _ if step & !(STEP_ACCOUNT | STEP_CURRENCY | STEP_ISSUER) != 0 => return None,
+            _ => cursor += 20 * step.count_ones() as usize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::currency::Currency;
+    use crate::core::types::opaque_float::OpaqueFloat;
+    use crate::sfield;
+
+    #[test]
+    fn test_encode_field_header_forms() {
+        assert_eq!(encode_field_header(sfield::Sequence), ([0x24, 0, 0], 1));
+        assert_eq!(encode_field_header(sfield::OwnerCount), ([0x2D, 0, 0], 1));
+        // Field code >= 16
+        assert_eq!(
+            encode_field_header(sfield::LastLedgerSequence),
+            ([0x20, 0x1B, 0], 2)
+        );
+        // Type code >= 16
+        assert_eq!(
+            encode_field_header(sfield::TickSize),
+            ([0x00, 0x10, 0x10], 3)
+        );
+    }
+
+    #[test]
+    fn test_payment_like_object() {
+        let account = AccountID::from([0x11; 20]);
+        let mut builder: ObjectBuilder<128> = ObjectBuilder::new();
+        builder.add(sfield::TransactionType, &0u16).unwrap();
+        builder.add(sfield::Sequence, &7u32).unwrap();
+        builder
+            .add_amount(sfield::Amount, &Amount::XRP { num_drops: 100 })
+            .unwrap();
+        builder.add(sfield::Account, &account).unwrap();
+
+        let mut expected = [0u8; 3 + 5 + 9 + 22];
+        expected[..3].copy_from_slice(&[0x12, 0x00, 0x00]);
+        expected[3..8].copy_from_slice(&[0x24, 0, 0, 0, 7]);
+        expected[8..17].copy_from_slice(&[0x61, 0x40, 0, 0, 0, 0, 0, 0, 100]);
+        expected[17..19].copy_from_slice(&[0x81, 0x14]);
+        expected[19..].copy_from_slice(&[0x11; 20]);
+        assert_eq!(builder.as_slice(), &expected);
+    }
+
+    #[test]
+    fn test_add_amount_rejects_non_canonical_issued() {
+        use crate::host::error_codes::INVALID_FLOAT_INPUT;
+
+        // A non-canonical 1 * 10^0 must be normalized to 10^15 * 10^-15 before it is written.
+        let one_raw = (0xC000000000000000u64 | (97u64 << 54) | 1).to_be_bytes();
+        let amount = Amount::IOU {
+            amount: OpaqueFloat(one_raw),
+            issuer: AccountID::from([2u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        let mut builder: ObjectBuilder<64> = ObjectBuilder::new();
+        assert_eq!(
+            builder
+                .add_amount(sfield::Amount, &amount)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_FLOAT_INPUT
+        );
+
+        let mut builder: ObjectBuilder<64> = ObjectBuilder::new();
+        builder
+            .add_amount(sfield::Amount, &amount.canonicalize().unwrap())
+            .unwrap();
+        assert_eq!(builder.len(), 1 + 48);
+        let mut padded = [0u8; 48];
+        padded.copy_from_slice(&builder.as_slice()[1..]);
+        match Amount::from_bytes(&padded).unwrap() {
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                assert_eq!(amount.0, crate::core::types::opaque_float::FLOAT_ONE);
+                assert_eq!(issuer, AccountID::from([2u8; 20]));
+                assert_eq!(currency, Currency::from(*b"USD"));
+            }
+            _ => panic!("expected an issued amount"),
+        }
+    }
+
+    #[test]
+    fn test_builder_overflow_errors() {
+        let mut builder: ObjectBuilder<4> = ObjectBuilder::new();
+        assert!(builder.add(sfield::Sequence, &1u32).is_err());
+    }
+
+    #[test]
+    fn test_signer_entries_array() {
+        let mut entries: [ObjectBuilder<32>; 2] = [ObjectBuilder::new(), ObjectBuilder::new()];
+        for (weight, entry) in (1u16..).zip(entries.iter_mut()) {
+            entry.add(sfield::SignerWeight, &weight).unwrap();
+            entry
+                .add(sfield::Account, &AccountID::from([weight as u8; 20]))
+                .unwrap();
+        }
+        let mut builder: ObjectBuilder<128> = ObjectBuilder::new();
+        builder
+            .add_array(sfield::SignerEntries, sfield::SignerEntry, &entries)
+            .unwrap();
+
+        let mut expected = [0u8; 1 + 2 * 27 + 1];
+        expected[0] = 0xF4;
+        for (i, chunk) in expected[1..55].chunks_mut(27).enumerate() {
+            let weight = i as u8 + 1;
+            chunk[..5].copy_from_slice(&[0xEB, 0x13, 0x00, weight, 0x81]);
+            chunk[5] = 0x14;
+            chunk[6..26].copy_from_slice(&[weight; 20]);
+            chunk[26] = OBJECT_END_MARKER;
+        }
+        expected[55] = ARRAY_END_MARKER;
+        assert_eq!(builder.as_slice(), &expected);
+    }
+
+    #[test]
+    fn test_add_object_and_empty_array() {
+        let mut memo: ObjectBuilder<8> = ObjectBuilder::new();
+        memo.add(sfield::SignerWeight, &5u16).unwrap();
+        let mut builder: ObjectBuilder<16> = ObjectBuilder::new();
+        builder.add_object(sfield::Memo, &memo).unwrap();
+        builder
+            .add_array(sfield::Memos, sfield::Memo, &[] as &[ObjectBuilder<8>])
+            .unwrap();
+        assert_eq!(
+            builder.as_slice(),
+            &[
+                0xEA,
+                0x13,
+                0x00,
+                0x05,
+                OBJECT_END_MARKER,
+                0xF9,
+                ARRAY_END_MARKER
+            ]
+        );
+    }
+
+    /// The signature used in [`sample_payment`]; its contents are not checked by the parser.
+    const SAMPLE_SIGNATURE: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
+
+    /// A single-signed Payment of 1 XRP from the genesis account, in canonical field order.
+    fn sample_payment() -> Blob<256> {
+        let genesis = crate::decode_hex_20(b"B5F762798A53D543A014CAF8B297CFF8F2F937E8").unwrap();
+        let mut tx: Blob<256> = Blob::new();
+        for part in [
+            &[0x12, 0x00, 0x00][..],         // TransactionType: Payment
+            &[0x22, 0x80, 0x00, 0x00, 0x00], // Flags
+            &[0x24, 0x00, 0x00, 0x00, 0x01], // Sequence: 1
+            &[0x61, 0x40, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x42, 0x40], // Amount: 1,000,000 drops
+            &[0x68, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0C], // Fee: 12 drops
+            &[0x73, 0x21, 0x02],             // SigningPubKey
+            &[0xAA; 32],
+            &[0x74, SAMPLE_SIGNATURE.len() as u8], // TxnSignature
+            &SAMPLE_SIGNATURE,
+            &[0x81, 0x14], // Account
+            &genesis,
+            &[0x83, 0x14], // Destination
+            &[0x22; 20],
+        ] {
+            tx.try_extend_from_slice(part).unwrap();
+        }
+        tx
+    }
+
+    #[test]
+    fn test_parse_known_payment() {
+        use sha2::{Digest, Sha512};
+
+        let bytes = sample_payment();
+        let tx = parse_transaction(bytes.as_slice()).unwrap();
+        assert_eq!(tx.tx_type().unwrap(), TransactionType::Payment);
+        assert_eq!(
+            tx.account().unwrap(),
+            AccountID(crate::decode_hex_20(b"B5F762798A53D543A014CAF8B297CFF8F2F937E8").unwrap())
+        );
+        assert_eq!(tx.get_field(sfield::Sequence).unwrap(), &[0, 0, 0, 1]);
+        assert_eq!(
+            tx.get_field(sfield::Amount).unwrap(),
+            &[0x40, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x42, 0x40]
+        );
+        assert_eq!(
+            tx.get_field(sfield::TxnSignature).unwrap(),
+            &SAMPLE_SIGNATURE
+        );
+        assert_eq!(tx.get_field(sfield::Destination).unwrap(), &[0x22; 20]);
+        assert_eq!(
+            tx.get_field(sfield::Memos).err().unwrap().code(),
+            crate::host::error_codes::FIELD_NOT_FOUND
+        );
+
+        // The host computes the hash itself, so check what it is given against a SHA-512Half of
+        // the same payment computed offline.
+        let mut data: Blob<256> = Blob::new();
+        tx.write_signing_data(&mut data).unwrap();
+        assert_eq!(
+            Sha512::digest(data.as_slice())[..32],
+            crate::decode_hex_32(
+                b"27012058B91D32549FBE7BA9C4EA0BFE87F0A5993426F625DE5A6D2F0EB58732"
+            )
+            .unwrap()
+        );
+        assert!(tx.signing_hash().is_ok());
+    }
+
+    #[test]
+    fn test_signing_data_omits_signature() {
+        let bytes = sample_payment();
+        let tx = parse_transaction(bytes.as_slice()).unwrap();
+        let mut data: Blob<256> = Blob::new();
+        tx.write_signing_data(&mut data).unwrap();
+
+        let signature_field = 2 + SAMPLE_SIGNATURE.len();
+        assert_eq!(
+            data.len(),
+            TX_SIGN_PREFIX.len() + bytes.len() - signature_field
+        );
+        assert_eq!(&data.as_slice()[..4], b"STX\0");
+        // Everything before the signature is kept in order, then everything after it.
+        let split = bytes
+            .as_slice()
+            .windows(2)
+            .position(|w| w == [0x74, SAMPLE_SIGNATURE.len() as u8])
+            .unwrap();
+        assert_eq!(&data.as_slice()[4..4 + split], &bytes.as_slice()[..split]);
+        assert_eq!(
+            &data.as_slice()[4 + split..],
+            &bytes.as_slice()[split + signature_field..]
+        );
+    }
+
+    #[test]
+    fn test_parse_nested_memos() {
+        let mut memo: ObjectBuilder<16> = ObjectBuilder::new();
+        memo.add(sfield::SignerWeight, &5u16).unwrap();
+        let mut builder: ObjectBuilder<64> = ObjectBuilder::new();
+        builder.add(sfield::TransactionType, &2u16).unwrap();
+        builder
+            .add_array(sfield::Memos, sfield::Memo, &[memo.clone(), memo])
+            .unwrap();
+
+        let tx = parse_transaction(builder.as_slice()).unwrap();
+        assert_eq!(tx.tx_type().unwrap(), TransactionType::EscrowFinish);
+        // Two Memo objects, each a 1-byte header, a 3-byte field and an end marker.
+        assert_eq!(tx.get_field(sfield::Memos).unwrap().len(), 2 * 5);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed() {
+        use crate::host::error_codes::{FIELD_NOT_FOUND, INVALID_DECODING};
+
+        let bytes = sample_payment();
+        // Truncated inside the Destination value.
+        assert_eq!(
+            parse_transaction(&bytes.as_slice()[..bytes.len() - 1])
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_DECODING
+        );
+        // A stray end marker at the top level.
+        assert_eq!(
+            parse_transaction(&[0x12, 0x00, 0x00, OBJECT_END_MARKER])
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_DECODING
+        );
+        // An unterminated array.
+        assert_eq!(
+            parse_transaction(&[0x12, 0x00, 0x00, 0xF9])
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_DECODING
+        );
+        // Well formed, but not a transaction.
+        assert_eq!(
+            parse_transaction(&[0x24, 0x00, 0x00, 0x00, 0x01])
+                .err()
+                .unwrap()
+                .code(),
+            FIELD_NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn test_decode_vl_length_forms() {
+        assert_eq!(decode_vl_length(&[192], 0), Some((192, 1)));
+        assert_eq!(decode_vl_length(&[193, 0], 0), Some((193, 2)));
+        assert_eq!(decode_vl_length(&[240, 255], 0), Some((12480, 2)));
+        assert_eq!(decode_vl_length(&[241, 0, 0], 0), Some((12481, 3)));
+        assert_eq!(decode_vl_length(&[255], 0), None);
+        assert_eq!(decode_vl_length(&[193], 0), None);
+    }
+}
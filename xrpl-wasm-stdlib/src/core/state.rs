@@ -0,0 +1,201 @@
+//! # Escrow State Patterns
+//!
+//! Helpers built on the current escrow's `Data` field (see
+//! [`CurrentEscrowFields::get_data`] and [`CurrentEscrowFields::update_current_escrow_data`]),
+//! which is the only state a smart escrow can persist between `finish` calls.
+//!
+//! ## One-shot actions
+//!
+//! [`once_per_escrow`] records a key the first time it is seen, so an action guarded by it runs
+//! at most once even if an `EscrowFinish` is retried. Keys are stored in `Data` as a list of
+//! records, each a one-byte length followed by that many key bytes; the list ends at the end of
+//! the data or at the first zero length byte.
+//!
+//! ## Atomicity
+//!
+//! The check and the write are two separate host calls. This is safe because the host runs one
+//! `finish` call at a time against a given escrow, so nothing can change `Data` between them.
+//! The guard is only as durable as the write, though: if the host discards the `Data` update
+//! (for example, because the transaction is not applied), the key is forgotten along with any
+//! effect of the action, and the next attempt sees it as new. Perform the guarded action only
+//! after `once_per_escrow` returns `Ok(true)`, and treat an error as "do not run".
+//!
+//! ```no_run
+//! use xrpl_wasm_stdlib::core::state::once_per_escrow;
+//! use xrpl_wasm_stdlib::host::Result;
+//!
+//! if let Result::Ok(true) = once_per_escrow(b"payout") {
+//!     // First time: perform the one-shot action.
+//! }
+//! ```
+
+use crate::core::ledger_objects::current_escrow::{CurrentEscrow, get_current_escrow};
+use crate::core::ledger_objects::traits::CurrentEscrowFields;
+use crate::core::types::contract_data::{ContractData, XRPL_CONTRACT_DATA_SIZE};
+use crate::host::{Error, Result};
+
+/// Longest key accepted by [`once_per_escrow`], in bytes.
+pub const MAX_ONCE_KEY_LEN: usize = u8::MAX as usize;
+
+/// Returns `Ok(true)` the first time it is called with `key` for the current escrow, and
+/// `Ok(false)` on every later call, including calls from later `finish` attempts.
+///
+/// The key is recorded in the escrow's `Data` field; see the [module docs](self) for the format
+/// and the atomicity assumptions. Other contents of `Data` that do not follow the record format
+/// are not supported.
+///
+/// # Errors
+///
+/// * `Error::InvalidParams` - `key` is empty or longer than [`MAX_ONCE_KEY_LEN`]
+/// * `Error::BufferTooSmall` - `Data` has no room left to record `key`
+/// * Any error from reading or updating the escrow's `Data` field
+pub fn once_per_escrow(key: &[u8]) -> Result<bool> {
+    let mut data = match get_current_escrow().get_data() {
+        Result::Ok(data) => data,
+        Result::Err(Error::FieldNotFound) => ContractData {
+            data: [0; XRPL_CONTRACT_DATA_SIZE],
+            len: 0,
+        },
+        Result::Err(e) => return Result::Err(e),
+    };
+    match record_once(&mut data, key) {
+        Result::Ok(false) => Result::Ok(false),
+        Result::Ok(true) => match CurrentEscrow::update_current_escrow_data(data) {
+            Result::Ok(()) => Result::Ok(true),
+            Result::Err(e) => Result::Err(e),
+        },
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Looks `key` up in the records held by `data`, appending it if absent.
+///
+/// Returns `Ok(true)` if the key was appended (and `data.len` now ends just after it), or
+/// `Ok(false)` if it was already recorded, in which case `data` is left unchanged.
+fn record_once(data: &mut ContractData, key: &[u8]) -> Result<bool> {
+    if key.is_empty() || key.len() > MAX_ONCE_KEY_LEN {
+        return Result::Err(Error::InvalidParams);
+    }
+    let used = data.len.min(XRPL_CONTRACT_DATA_SIZE);
+    let mut pos = 0;
+    while pos < used {
+        let len = data.data[pos] as usize;
+        if len == 0 {
+            break;
+        }
+        let start = pos + 1;
+        let end = start + len;
+        if end > used {
+            // A truncated trailing record; overwrite it.
+            break;
+        }
+        if &data.data[start..end] == key {
+            return Result::Ok(false);
+        }
+        pos = end;
+    }
+
+    let end = pos + 1 + key.len();
+    if end > XRPL_CONTRACT_DATA_SIZE {
+        return Result::Err(Error::BufferTooSmall);
+    }
+    data.data[pos] = key.len() as u8;
+    data.data[pos + 1..end].copy_from_slice(key);
+    data.len = end;
+    Result::Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::{BUFFER_TOO_SMALL, INVALID_PARAMS};
+
+    fn empty_data() -> ContractData {
+        ContractData {
+            data: [0; XRPL_CONTRACT_DATA_SIZE],
+            len: 0,
+        }
+    }
+
+    #[test]
+    fn test_record_once_two_invocations() {
+        // Each invocation starts from the Data the previous one wrote back.
+        let mut data = empty_data();
+        assert!(record_once(&mut data, b"payout").unwrap());
+        assert_eq!(data.len, 7);
+        assert_eq!(&data.data[..7], b"\x06payout");
+
+        let stored = data.clone();
+        assert!(!record_once(&mut data, b"payout").unwrap());
+        assert_eq!(data, stored);
+    }
+
+    #[test]
+    fn test_record_once_keys_are_independent() {
+        let mut data = empty_data();
+        assert!(record_once(&mut data, b"a").unwrap());
+        assert!(record_once(&mut data, b"ab").unwrap());
+        assert!(!record_once(&mut data, b"a").unwrap());
+        assert!(!record_once(&mut data, b"ab").unwrap());
+        assert!(record_once(&mut data, b"b").unwrap());
+        assert_eq!(&data.data[..data.len], b"\x01a\x02ab\x01b");
+    }
+
+    #[test]
+    fn test_record_once_stops_at_zero_length() {
+        // A zero-filled buffer reported at full length holds no records.
+        let mut data = empty_data();
+        data.len = XRPL_CONTRACT_DATA_SIZE;
+        assert!(record_once(&mut data, b"payout").unwrap());
+        assert_eq!(data.len, 7);
+    }
+
+    #[test]
+    fn test_record_once_rejects_bad_keys() {
+        let mut data = empty_data();
+        assert_eq!(
+            record_once(&mut data, b"").err().unwrap().code(),
+            INVALID_PARAMS
+        );
+        let long = [1u8; MAX_ONCE_KEY_LEN + 1];
+        assert_eq!(
+            record_once(&mut data, &long).err().unwrap().code(),
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn test_record_once_full() {
+        let mut data = empty_data();
+        let key = [7u8; MAX_ONCE_KEY_LEN];
+        let mut recorded = 0;
+        loop {
+            let mut unique = key;
+            unique[0] = recorded;
+            match record_once(&mut data, &unique) {
+                Result::Ok(true) => recorded += 1,
+                Result::Ok(false) => panic!("key recorded twice"),
+                Result::Err(e) => {
+                    assert_eq!(e.code(), BUFFER_TOO_SMALL);
+                    break;
+                }
+            }
+        }
+        assert_eq!(
+            recorded as usize,
+            XRPL_CONTRACT_DATA_SIZE / (MAX_ONCE_KEY_LEN + 1)
+        );
+    }
+
+    #[test]
+    fn test_once_per_escrow_with_test_host() {
+        let host = crate::host::mock::MockHost::new();
+        // A fresh escrow has no `Data`; the first call records the key with `update_data`, which
+        // the mock host persists, so the second call finds it.
+        host.remove_current_ledger_obj_field(crate::sfield::Data);
+        assert!(once_per_escrow(b"payout").unwrap());
+        assert!(!once_per_escrow(b"payout").unwrap());
+        assert!(once_per_escrow(b"refund").unwrap());
+        assert_eq!(once_per_escrow(b"").err().unwrap().code(), INVALID_PARAMS);
+    }
+}
@@ -15,8 +15,10 @@ pub const ACCOUNT_ID_SIZE: usize = 20;
 ///
 /// - `Copy`: Efficient for this 20-byte struct, enabling implicit copying
 /// - `PartialEq, Eq`: Enable comparisons and use in hash-based collections
+/// - `PartialOrd, Ord`: Compare IDs as big-endian 160-bit integers, the order rippled uses
+///   when placing accounts in directories
 /// - `Debug, Clone`: Standard traits for development and consistency
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(C)]
 pub struct AccountID(pub [u8; ACCOUNT_ID_SIZE]);
 
@@ -25,3 +27,178 @@ impl From<[u8; ACCOUNT_ID_SIZE]> for AccountID {
         AccountID(value)
     }
 }
+
+impl AccountID {
+    /// Returns the raw AccountID as a byte array.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; ACCOUNT_ID_SIZE] {
+        &self.0
+    }
+
+    /// Returns a pointer to the AccountID data.
+    ///
+    /// This is primarily used internally for FFI calls to host functions.
+    #[inline]
+    pub const fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    /// Returns the length of the AccountID (always 20 bytes).
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        ACCOUNT_ID_SIZE
+    }
+
+    /// Returns the number of leading zero bits, treating the ID as a big-endian integer.
+    ///
+    /// Account Zero returns 160.
+    pub fn leading_zero_bits(&self) -> u32 {
+        crate::core::types::uint::leading_zero_bits(&self.0)
+    }
+
+    /// Returns the issuer of `nft`, decoded from its NFTokenID without calling the host.
+    ///
+    /// This is [`NFToken::issuer_local`](crate::core::types::nft::NFToken::issuer_local) named
+    /// from the account side, e.g. for grouping tokens by issuer.
+    #[inline]
+    pub const fn from_nft(nft: &crate::core::types::nft::NFToken) -> AccountID {
+        nft.issuer_local()
+    }
+}
+
+/// Formats the ID as 40 upper-case hex characters, e.g. for use with [`trace!`](crate::trace).
+impl core::fmt::Display for AccountID {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.0 {
+            write!(f, "{byte:02X}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl AccountID {
+    /// Decodes a classic `r...` address into an `AccountID`.
+    ///
+    /// This is a runtime counterpart to the [`r_address!`](crate::r_address) macro for host-side
+    /// tooling (for example, reading issuers from a configuration file). It is not available
+    /// when compiling to WASM.
+    ///
+    /// Returns `Error::InvalidAccount` if the address is not valid base58, does not carry the
+    /// AccountID version byte, has the wrong length, or fails its checksum.
+    pub fn from_classic_address(address: &str) -> crate::host::Result<AccountID> {
+        use crate::host::{Error, Result};
+        use sha2::{Digest, Sha256};
+
+        // Version (1) + AccountID (20) + checksum (4)
+        let mut full = [0u8; 1 + ACCOUNT_ID_SIZE + 4];
+        let decoded_len = match bs58::decode(address)
+            .with_alphabet(bs58::Alphabet::RIPPLE)
+            .onto(&mut full)
+        {
+            Ok(len) => len,
+            Err(_) => return Result::Err(Error::InvalidAccount),
+        };
+        if !address.starts_with('r') || decoded_len != full.len() || full[0] != 0x00 {
+            return Result::Err(Error::InvalidAccount);
+        }
+
+        let (payload, checksum) = full.split_at(1 + ACCOUNT_ID_SIZE);
+        let digest = Sha256::digest(Sha256::digest(payload));
+        if &digest[..4] != checksum {
+            return Result::Err(Error::InvalidAccount);
+        }
+
+        let mut account = [0u8; ACCOUNT_ID_SIZE];
+        account.copy_from_slice(&payload[1..]);
+        Result::Ok(AccountID(account))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::test_fmt;
+    use crate::host::error_codes::INVALID_ACCOUNT;
+
+    #[test]
+    fn test_bytes_ptr_and_len() {
+        let account = AccountID([0xAB; ACCOUNT_ID_SIZE]);
+        assert_eq!(account.as_bytes(), &[0xAB; ACCOUNT_ID_SIZE]);
+        assert_eq!(account.as_ptr(), account.0.as_ptr());
+        assert_eq!(account.len(), ACCOUNT_ID_SIZE);
+    }
+
+    #[test]
+    fn test_from_nft_is_const() {
+        const NFT: crate::core::types::nft::NFToken = crate::core::types::nft::NFToken::new([
+            0x00, 0x08, 0x00, 0x00, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11,
+            0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x22, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x01,
+        ]);
+        const ISSUER: AccountID = AccountID::from_nft(&NFT);
+        let mut expected = [0x11u8; ACCOUNT_ID_SIZE];
+        expected[19] = 0x22;
+        assert_eq!(ISSUER, AccountID(expected));
+    }
+
+    #[test]
+    fn test_account_id_ordering_and_leading_zero_bits() {
+        let zero = AccountID([0u8; ACCOUNT_ID_SIZE]);
+        let mut one = [0u8; ACCOUNT_ID_SIZE];
+        one[19] = 1;
+        let one = AccountID(one);
+        let genesis = AccountID(crate::r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
+
+        assert!(zero < one);
+        assert!(one < genesis);
+        assert_eq!(zero.leading_zero_bits(), 160);
+        assert_eq!(one.leading_zero_bits(), 159);
+        // The genesis account starts with 0xB5.
+        assert_eq!(genesis.leading_zero_bits(), 0);
+    }
+
+    #[test]
+    fn test_display_is_upper_hex() {
+        let mut bytes = [0u8; ACCOUNT_ID_SIZE];
+        bytes[0] = 0xB5;
+        bytes[19] = 0x0A;
+        let mut out = [0u8; 2 * ACCOUNT_ID_SIZE];
+        assert_eq!(
+            test_fmt::format(&mut out, format_args!("{}", AccountID(bytes))),
+            "B50000000000000000000000000000000000000A"
+        );
+    }
+
+    #[test]
+    fn test_from_classic_address_matches_macro() {
+        let expected = AccountID(crate::r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
+        assert_eq!(
+            AccountID::from_classic_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap(),
+            expected
+        );
+        assert_eq!(
+            AccountID::from_classic_address("rrrrrrrrrrrrrrrrrrrrrhoLvTp").unwrap(),
+            AccountID([0u8; ACCOUNT_ID_SIZE])
+        );
+    }
+
+    #[test]
+    fn test_from_classic_address_rejects_bad_input() {
+        for address in [
+            "",
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi", // bad checksum
+            "rHb9CJAWyB4rj91VRWn96DkukG4bwdty",   // truncated
+            "0Hb9CJAWyB4rj91VRWn96DkukG4bwdtyTh", // not in the alphabet
+        ] {
+            assert_eq!(
+                AccountID::from_classic_address(address)
+                    .err()
+                    .unwrap()
+                    .code(),
+                INVALID_ACCOUNT
+            );
+        }
+    }
+}
@@ -1,9 +1,15 @@
+use crate::core::constants::{DROPS_PER_XRP, MAX_DROPS};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::core::types::account_id::ACCOUNT_ID_SIZE;
 use crate::core::types::account_id::AccountID;
 use crate::core::types::currency::Currency;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::core::types::currency::{CURRENCY_SIZE, STANDARD_CURRENCY_SIZE};
 use crate::core::types::mpt_id::MptId;
 use crate::core::types::opaque_float::OpaqueFloat;
 use crate::host;
 use crate::host::Error::InternalError;
+use crate::host::error_codes::match_result_code_with_expected_bytes;
 use crate::host::trace::trace_num;
 
 pub const AMOUNT_SIZE: usize = 48;
@@ -74,11 +80,13 @@ pub const AMOUNT_SIZE: usize = 48;
 ///
 /// ## Derived Traits
 ///
-/// - `PartialEq, Eq`: Enable comparisons and use in collections
 /// - `Debug, Clone`: Standard traits for development and consistency
 ///
+/// `PartialEq` and `Eq` are implemented by hand so that every encoding of an issued zero is
+/// equal (see the impl), and `Display` renders exact decimal text.
+///
 /// Note: `Copy` is intentionally not derived due to the enum's size (48 bytes).
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 #[repr(C)]
 pub enum Amount {
     XRP {
@@ -107,6 +115,55 @@ pub enum Amount {
 
 const MASK_57_BIT: u64 = 0x01FFFFFFFFFFFFFFu64;
 
+/// Smallest mantissa of a canonical, non-zero issued amount (10^15).
+const IOU_MIN_MANTISSA: u64 = 1_000_000_000_000_000;
+/// Largest mantissa of a canonical issued amount (10^16 - 1).
+const IOU_MAX_MANTISSA: u64 = 9_999_999_999_999_999;
+/// Smallest exponent of a canonical, non-zero issued amount.
+const IOU_MIN_EXPONENT: i32 = -96;
+/// Largest exponent of a canonical issued amount.
+const IOU_MAX_EXPONENT: i32 = 80;
+/// Bias added to the exponent when it is stored in the 8-bit exponent field.
+const IOU_EXPONENT_BIAS: i32 = 97;
+/// Mask for the 54-bit mantissa of an issued amount.
+const IOU_MANTISSA_MASK: u64 = (1u64 << 54) - 1;
+/// The canonical encoding of an issued amount of zero.
+const IOU_ZERO: u64 = 0x8000000000000000;
+
+/// Rewrites an issued-amount float into rippled's canonical form.
+///
+/// Canonical non-zero values have a mantissa in `10^15..10^16` and an exponent in `-96..=80`.
+/// Following rippled's `STAmount::canonicalize`, excess digits are truncated, values too small
+/// to represent become zero (which is always positive), and values too large to represent
+/// return `Error::InvalidFloatInput`.
+pub(crate) fn canonicalize_iou(float: &OpaqueFloat) -> host::Result<OpaqueFloat> {
+    let bits = u64::from_be_bytes(float.0);
+    canonicalize_iou_parts(
+        bits & 0x4000000000000000 != 0,
+        bits & IOU_MANTISSA_MASK,
+        ((bits >> 54) & 0xFF) as i32 - IOU_EXPONENT_BIAS,
+    )
+}
+
+/// Builds a canonical issued-amount float from a sign, mantissa and exponent that need not be in
+/// canonical range, following the same rules as [`canonicalize_iou`].
+fn canonicalize_iou_parts(
+    is_positive: bool,
+    mantissa: u64,
+    exponent: i32,
+) -> host::Result<OpaqueFloat> {
+    let (mantissa, exponent) = match Amount::normalize_issued(mantissa, exponent) {
+        host::Result::Ok((0, _)) => return host::Result::Ok(OpaqueFloat(IOU_ZERO.to_be_bytes())),
+        host::Result::Ok(parts) => parts,
+        host::Result::Err(e) => return host::Result::Err(e),
+    };
+    let mut bits = IOU_ZERO | ((exponent + IOU_EXPONENT_BIAS) as u64) << 54 | mantissa;
+    if is_positive {
+        bits |= 0x4000000000000000;
+    }
+    host::Result::Ok(OpaqueFloat(bits.to_be_bytes()))
+}
+
 impl Amount {
     /// Converts a Amount to STAmount bytes format.
     ///
@@ -165,7 +222,7 @@ impl Amount {
             } => {
                 // IOU format for tracing: opaque float + currency + issuer
                 bytes[0..8].copy_from_slice(&amount.0);
-                bytes[8..28].copy_from_slice(currency.as_bytes());
+                bytes[8..28].copy_from_slice(&currency.to_bytes());
                 bytes[28..48].copy_from_slice(&issuer.0);
                 // No padding needed - uses all 48 bytes
             }
@@ -174,6 +231,32 @@ impl Amount {
         (bytes, AMOUNT_SIZE)
     }
 
+    /// Converts this Amount to its canonical serialized STAmount encoding.
+    ///
+    /// Unlike [`Amount::to_stamount_bytes`], which always reports 48 bytes, the returned length is
+    /// the exact serialized size of the amount:
+    /// - XRP: 8 bytes
+    /// - MPT: 33 bytes
+    /// - IOU: 48 bytes, with the float normalized to rippled's canonical form (mantissa in
+    ///   `10^15..10^16`, exponent in `-96..=80`)
+    ///
+    /// Returns `Error::InvalidFloatInput` if an issued amount is too large to be represented.
+    pub fn to_serialized_bytes(&self) -> host::Result<([u8; AMOUNT_SIZE], usize)> {
+        let (mut bytes, _) = self.to_stamount_bytes();
+        let len = match self {
+            Amount::XRP { .. } => 8,
+            Amount::MPT { .. } => 33,
+            Amount::IOU { amount, .. } => {
+                match canonicalize_iou(amount) {
+                    host::Result::Ok(canonical) => bytes[0..8].copy_from_slice(&canonical.0),
+                    host::Result::Err(e) => return host::Result::Err(e),
+                }
+                AMOUNT_SIZE
+            }
+        };
+        host::Result::Ok((bytes, len))
+    }
+
     /// Parses a Amount from a byte array.
     ///
     /// The byte array can be one of three formats:
@@ -253,9 +336,10 @@ impl Amount {
             // amount_bytes.copy_from_slice(&bytes[0..9]);
 
             // Parse the Currency from the next 20 bytes
-            let mut currency_bytes = [0u8; 20];
-            currency_bytes.copy_from_slice(&bytes[8..28]);
-            let currency = Currency::from(currency_bytes);
+            let currency = match Currency::from_amount_bytes(&bytes[..48]) {
+                Some(currency) => currency,
+                None => return Err(InternalError),
+            };
 
             // Parse the AccountID from the last 20 bytes
             let mut issuer_bytes = [0u8; 20];
@@ -271,469 +355,2844 @@ impl Amount {
             Ok(amount)
         }
     }
-}
 
-impl From<[u8; AMOUNT_SIZE]> for Amount {
-    fn from(bytes: [u8; AMOUNT_SIZE]) -> Self {
-        // Use the existing from_bytes method with a slice reference
-        match Self::from_bytes(&bytes) {
+    /// Reads the amount field `field_code` from the current transaction.
+    ///
+    /// The host writes the field in its serialized form, whose length depends on the kind of
+    /// amount: 8 bytes for XRP, 33 for MPT and 48 for issued amounts. The kind is detected from
+    /// the leading flag bits and the length is checked against it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Amount)` - The decoded amount
+    /// * `Err(Error::FieldNotFound)` - The transaction does not carry the field
+    /// * `Err(Error::InvalidField)` - The field's length does not match its amount kind, which
+    ///   usually means `field_code` is not an amount field
+    /// * `Err(Error::InvalidDecoding)` - The amount is malformed: reserved bits are set, or an
+    ///   issued amount has a non-canonical value or a currency that cannot be issued
+    /// * `Err(Error)` - Any other error reported by the host
+    pub fn from_tx_field(field_code: i32) -> host::Result<Amount> {
+        let mut buffer = [0u8; AMOUNT_SIZE];
+        let result_code =
+            unsafe { host::get_tx_field(field_code, buffer.as_mut_ptr(), buffer.len()) };
+        if result_code < 0 {
+            return host::Result::Err(host::Error::from_code(result_code));
+        }
+        Amount::from_field_bytes(&buffer[..(result_code as usize).min(AMOUNT_SIZE)])
+    }
+
+    /// Decodes a serialized amount field of exactly 8, 33 or 48 bytes, as described in
+    /// [`Amount::from_tx_field`].
+    fn from_field_bytes(bytes: &[u8]) -> host::Result<Amount> {
+        let Some(&byte0) = bytes.first() else {
+            return host::Result::Err(host::Error::InvalidField);
+        };
+        let (expected_len, reserved_bits) = match (byte0 & 0x80 != 0, byte0 & 0x20 != 0) {
+            (true, _) => (AMOUNT_SIZE, 0),
+            (false, false) => (8, 0x1E),
+            (false, true) => (33, 0x1F),
+        };
+        if bytes.len() != expected_len {
+            return host::Result::Err(host::Error::InvalidField);
+        }
+        if byte0 & reserved_bits != 0 {
+            return host::Result::Err(host::Error::InvalidDecoding);
+        }
+
+        let mut padded = [0u8; AMOUNT_SIZE];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        let amount = match Amount::from_bytes(&padded) {
             Ok(amount) => amount,
-            Err(error) => {
-                let _ = trace_num("Error parsing amount", error.code() as i64);
-                panic!("Invalid Amount byte array");
+            Err(_) => return host::Result::Err(host::Error::InvalidDecoding),
+        };
+        if let Amount::IOU { currency, .. } = &amount
+            && (!amount.is_canonical() || !currency.is_valid_issued())
+        {
+            return host::Result::Err(host::Error::InvalidDecoding);
+        }
+        host::Result::Ok(amount)
+    }
+
+    /// Returns `true` if this amount is in the canonical form the host accepts.
+    ///
+    /// An issued amount is canonical if it is exactly the canonical zero, or its mantissa is in
+    /// `10^15..10^16` and its exponent in `-96..=80`. XRP and MPT amounts are plain integers and
+    /// always canonical.
+    pub fn is_canonical(&self) -> bool {
+        match self {
+            Amount::IOU { amount, .. } => {
+                let bits = u64::from_be_bytes(amount.0);
+                let mantissa = bits & IOU_MANTISSA_MASK;
+                let exponent = ((bits >> 54) & 0xFF) as i32 - IOU_EXPONENT_BIAS;
+                if bits & 0x8000000000000000 == 0 {
+                    return false;
+                }
+                if mantissa == 0 {
+                    return bits == IOU_ZERO;
+                }
+                (IOU_MIN_MANTISSA..=IOU_MAX_MANTISSA).contains(&mantissa)
+                    && (IOU_MIN_EXPONENT..=IOU_MAX_EXPONENT).contains(&exponent)
             }
+            Amount::XRP { .. } | Amount::MPT { .. } => true,
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::core::types::opaque_float::OpaqueFloat;
+    /// Returns `true` if `self` and `other` are amounts of the same asset: both XRP, issued
+    /// amounts with the same currency and issuer, or MPT amounts of the same issuance.
+    pub fn is_same_asset(&self, other: &Amount) -> bool {
+        match (self, other) {
+            (Amount::XRP { .. }, Amount::XRP { .. }) => true,
+            (
+                Amount::IOU {
+                    issuer, currency, ..
+                },
+                Amount::IOU {
+                    issuer: other_issuer,
+                    currency: other_currency,
+                    ..
+                },
+            ) => issuer == other_issuer && currency == other_currency,
+            (Amount::MPT { mpt_id, .. }, Amount::MPT { mpt_id: other, .. }) => mpt_id == other,
+            _ => false,
+        }
+    }
 
-    #[test]
-    fn test_parse_xrp_amount() {
-        // Create a test XRP amount byte array
-        // XRP amount: [0/type][1/sign][0/is-mpt][4/reserved][57/value]
-        // First byte: 0b0100_0000 (0x40)
-        // Value: 1,000,000 (0xF4240 in hex)
-        let mut bytes = [0u8; 48];
-        bytes[0] = 0x40; // XRP positive flag
-        bytes[1..8].copy_from_slice(&1_000_000u64.to_be_bytes()[1..8]);
+    /// Returns this amount in canonical form (see [`Amount::is_canonical`]).
+    ///
+    /// Issued amounts are normalized following rippled's `STAmount::canonicalize`: excess
+    /// digits are truncated and values too small to represent become zero. Values too large to
+    /// represent return `Error::InvalidFloatInput`. XRP and MPT amounts are returned unchanged.
+    pub fn canonicalize(&self) -> host::Result<Amount> {
+        match self {
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => match canonicalize_iou(amount) {
+                host::Result::Ok(amount) => host::Result::Ok(Amount::IOU {
+                    amount,
+                    issuer: *issuer,
+                    currency: *currency,
+                }),
+                host::Result::Err(e) => host::Result::Err(e),
+            },
+            Amount::XRP { .. } | Amount::MPT { .. } => host::Result::Ok(self.clone()),
+        }
+    }
 
-        // Parse the Amount
-        let amount = Amount::from_bytes(&bytes).unwrap();
+    /// Normalizes the value `mantissa * 10^exponent` into the canonical range of an issued
+    /// amount, returning the new `(mantissa, exponent)`.
+    ///
+    /// A non-zero result has a mantissa in `10^15..10^16` and an exponent in `-96..=80`. As in
+    /// rippled's `STAmount::canonicalize`, digits shifted out of the mantissa are truncated, and
+    /// a value too small to represent becomes zero, which is returned as `(0, 0)`. A value too
+    /// large to represent returns `Error::InvalidFloatInput`.
+    ///
+    /// Every issued amount this crate encodes (see [`Amount::canonicalize`]) goes through this
+    /// function.
+    pub fn normalize_issued(mut mantissa: u64, mut exponent: i32) -> host::Result<(u64, i32)> {
+        if mantissa == 0 {
+            return host::Result::Ok((0, 0));
+        }
+        while mantissa < IOU_MIN_MANTISSA && exponent > IOU_MIN_EXPONENT {
+            mantissa *= 10;
+            exponent -= 1;
+        }
+        while mantissa > IOU_MAX_MANTISSA {
+            if exponent >= IOU_MAX_EXPONENT {
+                return host::Result::Err(host::Error::InvalidFloatInput);
+            }
+            mantissa /= 10;
+            exponent += 1;
+        }
+        if exponent < IOU_MIN_EXPONENT || mantissa < IOU_MIN_MANTISSA {
+            return host::Result::Ok((0, 0));
+        }
+        if exponent > IOU_MAX_EXPONENT {
+            return host::Result::Err(host::Error::InvalidFloatInput);
+        }
+        host::Result::Ok((mantissa, exponent))
+    }
 
-        // Verify it's an XRP amount with the correct value
-        match amount {
+    /// Creates an issued (IOU) amount, rejecting currency codes that cannot be issued.
+    ///
+    /// Returns `Error::InvalidParams` if `currency` fails [`Currency::is_valid_issued`], such as
+    /// the reserved `"XRP"` code, which the host would reject anyway.
+    pub fn from_issued(
+        amount: OpaqueFloat,
+        issuer: AccountID,
+        currency: Currency,
+    ) -> host::Result<Amount> {
+        if !currency.is_valid_issued() {
+            return host::Result::Err(host::Error::InvalidParams);
+        }
+        host::Result::Ok(Amount::IOU {
+            amount,
+            issuer,
+            currency,
+        })
+    }
+
+    /// Returns `true` if this XRP amount is strictly smaller than `threshold_drops`.
+    ///
+    /// The comparison uses the magnitude of the amount, so `-5` drops is dust under a threshold
+    /// of `10`. An amount exactly equal to the threshold is not dust.
+    ///
+    /// Only XRP amounts can be compared against a drop threshold. IOU and MPT amounts are
+    /// denominated in a different asset, so they return `Error::InvalidParams` rather than
+    /// guessing at an exchange rate.
+    pub fn is_dust(&self, threshold_drops: u64) -> host::Result<bool> {
+        match self {
             Amount::XRP { num_drops } => {
-                assert_eq!(num_drops, 1_000_000);
+                host::Result::Ok(num_drops.unsigned_abs() < threshold_drops)
+            }
+            Amount::IOU { .. } | Amount::MPT { .. } => {
+                host::Result::Err(host::Error::InvalidParams)
             }
-            _ => panic!("Expected Amount::XRP"),
         }
     }
 
-    #[test]
-    fn test_parse_mpt_amount() {
-        // Create a test MPT amount byte array
-        // MPT amount: [0/type][1/sign][1/is-mpt][5/reserved][64/value][32/sequence][160/issuer]
-        // First byte: 0b0110_0000 (0x60)
-        const VALUE: u64 = 500_000; // 8 bytes
-        const SEQUENCE_NUM: u32 = 12345; // 4 bytes
-        const ISSUER_BYTES: [u8; 20] = [1u8; 20]; // 20 bytes
-
-        let mut bytes = [0u8; 48];
+    /// Compares this XRP amount with `threshold` drops, e.g. `cmp_drops(min)? != Ordering::Less`
+    /// for "at least `min` drops".
+    ///
+    /// Unlike [`Amount::is_dust`], the comparison is signed: a negative amount is less than every
+    /// threshold. IOU and MPT amounts return `Error::InvalidParams`.
+    pub fn cmp_drops(&self, threshold: u64) -> host::Result<core::cmp::Ordering> {
+        match self {
+            Amount::XRP { num_drops } => {
+                host::Result::Ok((*num_drops as i128).cmp(&(threshold as i128)))
+            }
+            Amount::IOU { .. } | Amount::MPT { .. } => {
+                host::Result::Err(host::Error::InvalidParams)
+            }
+        }
+    }
 
-        // Set the amount bytes
-        bytes[0] = 0x60; // MPT positive flag
-        bytes[1..9].copy_from_slice(&VALUE.to_be_bytes());
+    /// Subtracts `other` from `self`, producing a result that may be negative.
+    ///
+    /// Both amounts must be of the same asset: XRP with XRP, the same currency and issuer for
+    /// IOUs, or the same `MptId` for MPTs. Mixing assets returns `Error::InvalidParams`.
+    ///
+    /// * **XRP**: The `XRP` variant already stores a signed `i64` drop count, so a result below
+    ///   zero is returned as a negative `Amount::XRP` rather than an error. Only `i64` overflow
+    ///   returns `Error::InvalidParams`.
+    /// * **IOU**: The subtraction is performed by the host (`float_subtract`) so the result
+    ///   matches rippled's `Number` rounding exactly; the sign is carried in the float itself.
+    /// * **MPT**: The sign is carried in `is_positive`. A zero result is positive. Results whose
+    ///   magnitude does not fit in a `u64` return `Error::InvalidParams`.
+    pub fn signed_sub(&self, other: &Amount) -> host::Result<Amount> {
+        match (self, other) {
+            (Amount::XRP { num_drops: a }, Amount::XRP { num_drops: b }) => match a.checked_sub(*b)
+            {
+                Some(num_drops) => host::Result::Ok(Amount::XRP { num_drops }),
+                None => host::Result::Err(host::Error::InvalidParams),
+            },
+            (
+                Amount::IOU {
+                    amount: a,
+                    issuer,
+                    currency,
+                },
+                Amount::IOU {
+                    amount: b,
+                    issuer: other_issuer,
+                    currency: other_currency,
+                },
+            ) => {
+                if issuer != other_issuer || currency != other_currency {
+                    return host::Result::Err(host::Error::InvalidParams);
+                }
+                let mut out = [0u8; 8];
+                let result_code = unsafe {
+                    host::float_subtract(
+                        a.0.as_ptr(),
+                        a.0.len(),
+                        b.0.as_ptr(),
+                        b.0.len(),
+                        out.as_mut_ptr(),
+                        out.len(),
+                        host::FLOAT_ROUNDING_MODES_TO_NEAREST,
+                    )
+                };
+                match_result_code_with_expected_bytes(result_code, 8, || Amount::IOU {
+                    amount: OpaqueFloat(out),
+                    issuer: *issuer,
+                    currency: *currency,
+                })
+            }
+            (
+                Amount::MPT {
+                    num_units: a,
+                    is_positive: a_positive,
+                    mpt_id,
+                },
+                Amount::MPT {
+                    num_units: b,
+                    is_positive: b_positive,
+                    mpt_id: other_mpt_id,
+                },
+            ) => {
+                if mpt_id != other_mpt_id {
+                    return host::Result::Err(host::Error::InvalidParams);
+                }
+                let signed = |units: u64, positive: bool| match positive {
+                    true => units as i128,
+                    false => -(units as i128),
+                };
+                let diff = signed(*a, *a_positive) - signed(*b, *b_positive);
+                match u64::try_from(diff.unsigned_abs()) {
+                    Ok(num_units) => host::Result::Ok(Amount::MPT {
+                        num_units,
+                        is_positive: diff >= 0,
+                        mpt_id: *mpt_id,
+                    }),
+                    Err(_) => host::Result::Err(host::Error::InvalidParams),
+                }
+            }
+            _ => host::Result::Err(host::Error::InvalidParams),
+        }
+    }
 
-        // Set the MptId bytes
-        bytes[9..13].copy_from_slice(&SEQUENCE_NUM.to_be_bytes());
-        // Set the Issuer bytes.
-        bytes[13..33].copy_from_slice(&ISSUER_BYTES);
+    /// Subtracts `other` from `self`. This is [`Amount::signed_sub`] under the name used by the
+    /// rest of the `checked_*` family.
+    #[inline]
+    pub fn checked_sub(&self, other: &Amount) -> host::Result<Amount> {
+        self.signed_sub(other)
+    }
 
-        // Parse the Amount
-        let amount = Amount::from_bytes(&bytes).unwrap();
+    /// Adds `other` to `self`.
+    ///
+    /// Follows the same asset and sign rules as [`Amount::signed_sub`]: mixing assets or
+    /// overflowing returns `Error::InvalidParams`, and issued amounts are added by the host
+    /// (`float_subtract` of the negated operand) so rounding matches rippled.
+    pub fn checked_add(&self, other: &Amount) -> host::Result<Amount> {
+        match other.checked_neg() {
+            host::Result::Ok(negated) => self.signed_sub(&negated),
+            host::Result::Err(e) => host::Result::Err(e),
+        }
+    }
 
-        // Verify it's an MPT amount with the correct values
-        match amount {
+    /// Returns the amount with its sign flipped. Zero stays zero (and positive).
+    ///
+    /// Only an XRP amount of `i64::MIN` drops, which is far outside the XRP supply, cannot be
+    /// negated; it returns `Error::InvalidParams`.
+    pub fn checked_neg(&self) -> host::Result<Amount> {
+        match self {
+            Amount::XRP { num_drops } => match num_drops.checked_neg() {
+                Some(num_drops) => host::Result::Ok(Amount::XRP { num_drops }),
+                None => host::Result::Err(host::Error::InvalidParams),
+            },
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                let bits = u64::from_be_bytes(amount.0);
+                let bits = match bits & IOU_MANTISSA_MASK {
+                    0 => bits,
+                    _ => bits ^ 0x4000000000000000,
+                };
+                host::Result::Ok(Amount::IOU {
+                    amount: OpaqueFloat(bits.to_be_bytes()),
+                    issuer: *issuer,
+                    currency: *currency,
+                })
+            }
             Amount::MPT {
                 num_units,
                 is_positive,
                 mpt_id,
-            } => {
-                assert_eq!(num_units, VALUE);
-                assert!(is_positive);
-                assert_eq!(mpt_id.get_sequence_num(), SEQUENCE_NUM);
-                assert_eq!(mpt_id.get_issuer(), AccountID::from(ISSUER_BYTES));
+            } => host::Result::Ok(Amount::MPT {
+                num_units: *num_units,
+                is_positive: !*is_positive || *num_units == 0,
+                mpt_id: *mpt_id,
+            }),
+        }
+    }
+
+    /// The release-build fallback of the `+` and `-` operators when `checked_add`/`checked_sub`
+    /// fails. See the [`core::ops::Add`] impl for the policy.
+    fn saturating_combine(&self, other: &Amount, subtract: bool) -> Amount {
+        match (self, other) {
+            (Amount::XRP { num_drops: a }, Amount::XRP { num_drops: b }) => Amount::XRP {
+                num_drops: match subtract {
+                    true => a.saturating_sub(*b),
+                    false => a.saturating_add(*b),
+                },
+            },
+            (
+                Amount::MPT {
+                    num_units: a,
+                    is_positive: a_positive,
+                    mpt_id,
+                },
+                Amount::MPT {
+                    num_units: b,
+                    is_positive: b_positive,
+                    mpt_id: other_mpt_id,
+                },
+            ) if mpt_id == other_mpt_id => {
+                let signed = |units: u64, positive: bool| match positive {
+                    true => units as i128,
+                    false => -(units as i128),
+                };
+                let b = match subtract {
+                    true => -signed(*b, *b_positive),
+                    false => signed(*b, *b_positive),
+                };
+                let total = signed(*a, *a_positive) + b;
+                Amount::MPT {
+                    num_units: u64::try_from(total.unsigned_abs()).unwrap_or(u64::MAX),
+                    is_positive: total >= 0,
+                    mpt_id: *mpt_id,
+                }
             }
-            _ => panic!("Expected Amount::MPT"),
+            (Amount::IOU { amount, .. }, other) if self.is_same_asset(other) => {
+                // Two in-range operands can only overflow when their magnitudes add up, so the
+                // result takes the sign of `self`.
+                let is_positive = u64::from_be_bytes(amount.0) & 0x4000000000000000 != 0;
+                let mut bits = IOU_ZERO
+                    | ((IOU_MAX_EXPONENT + IOU_EXPONENT_BIAS) as u64) << 54
+                    | IOU_MAX_MANTISSA;
+                if is_positive {
+                    bits |= 0x4000000000000000;
+                }
+                match self {
+                    Amount::IOU {
+                        issuer, currency, ..
+                    } => Amount::IOU {
+                        amount: OpaqueFloat(bits.to_be_bytes()),
+                        issuer: *issuer,
+                        currency: *currency,
+                    },
+                    _ => unreachable!(),
+                }
+            }
+            _ => self.clone(),
         }
     }
 
-    #[test]
-    fn test_parse_iou_amount() {
-        // IOU with exponent = 5, mantissa = 12345
-        const EXPONENT: u8 = 5; // 1 byte
-        const MANTISSA: u64 = 12345; // 57 bits (so need or 8 bytes)
+    /// Applies `+` or `-` following the operator policy documented on [`core::ops::Add`].
+    fn combine_or_saturate(self, other: Amount, subtract: bool) -> Amount {
+        let result = match subtract {
+            true => self.checked_sub(&other),
+            false => self.checked_add(&other),
+        };
+        match result {
+            host::Result::Ok(amount) => amount,
+            host::Result::Err(_) => {
+                if cfg!(debug_assertions) {
+                    panic!("Amount arithmetic across different assets or out of range");
+                }
+                self.saturating_combine(&other, subtract)
+            }
+        }
+    }
 
-        // First byte: 0b1100_0000 (0xC0, flags for IOU positive)
-        // For exponent 5:
-        // - We need to set the last 6 bits of the first byte and first 2 bits of the second byte
-        // - 5 = 0b00000101, so we need 0b000001 in the last 6 bits of first byte
-        // - and 0b01 in the first 2 bits of second byte
+    /// Adds up `amounts`, which must all be of the same asset.
+    ///
+    /// Each step is an [`Amount::checked_add`], so mixing assets or overflowing returns
+    /// `Error::InvalidParams`. An empty slice also returns `Error::InvalidParams` rather than a
+    /// zero XRP amount: the asset of an empty sum is unknown, and defaulting to XRP would let a
+    /// missing list of issued-token payouts pass as "nothing owed" in XRP.
+    pub fn sum(amounts: &[Amount]) -> host::Result<Amount> {
+        let (first, rest) = match amounts.split_first() {
+            Some(split) => split,
+            None => return host::Result::Err(host::Error::InvalidParams),
+        };
+        let mut total = first.clone();
+        for amount in rest {
+            total = match total.checked_add(amount) {
+                host::Result::Ok(total) => total,
+                host::Result::Err(e) => return host::Result::Err(e),
+            };
+        }
+        host::Result::Ok(total)
+    }
 
-        // Create the input bytes
-        let mut input = [0u8; 9];
-        // Set the first byte: IOU positive flag (0xC0) with exponent bits
-        input[0] = 0xC0 | ((EXPONENT >> 2) & 0x3F); // 5 >> 2 = 1, so this is 0xC1
+    /// Returns the MPT issuance this amount is denominated in, or `None` for XRP and issued
+    /// amounts.
+    #[inline]
+    pub fn mpt_issuance_id(&self) -> Option<MptId> {
+        match self {
+            Amount::MPT { mpt_id, .. } => Some(*mpt_id),
+            Amount::XRP { .. } | Amount::IOU { .. } => None,
+        }
+    }
 
-        // Set the second byte: first 2 bits for exponent, rest will be part of mantissa
-        input[1] = (EXPONENT & 0x03) << 6; // 5 & 0x03 = 1, 1 << 6 = 0x40
+    /// Splits the amount into `n` equal shares, returning `(each, remainder)` such that
+    /// `each × n + remainder` is exactly `self`, so no drops are lost to rounding.
+    ///
+    /// * **XRP**: `each` is `drops / n` and `remainder` is `drops % n`. For a negative amount
+    ///   both carry its sign.
+    /// * **MPT**: The same integer division on the unit count, keeping the amount's sign.
+    /// * **IOU**: Issued amounts are decimal floats with no exact integer remainder, so they
+    ///   return `Error::InvalidParams`; divide with the host's `float_divide` instead.
+    ///
+    /// An `n` of 0 returns `Error::InvalidParams`.
+    pub fn split_even(&self, n: u32) -> host::Result<(Amount, Amount)> {
+        if n == 0 {
+            return host::Result::Err(host::Error::InvalidParams);
+        }
+        match self {
+            Amount::XRP { num_drops } => host::Result::Ok((
+                Amount::XRP {
+                    num_drops: num_drops / n as i64,
+                },
+                Amount::XRP {
+                    num_drops: num_drops % n as i64,
+                },
+            )),
+            Amount::MPT {
+                num_units,
+                is_positive,
+                mpt_id,
+            } => {
+                let share = |num_units: u64| Amount::MPT {
+                    num_units,
+                    is_positive: *is_positive || num_units == 0,
+                    mpt_id: *mpt_id,
+                };
+                host::Result::Ok((share(num_units / n as u64), share(num_units % n as u64)))
+            }
+            Amount::IOU { .. } => host::Result::Err(host::Error::InvalidParams),
+        }
+    }
 
-        let mantissa_bytes = MANTISSA.to_be_bytes();
+    /// Returns `self` as a share of `total`, in basis points (1/100 of a percent), rounded down.
+    ///
+    /// `10_000` means `self` equals `total`. Amounts larger than `total` give results above
+    /// `10_000`, saturating at `u32::MAX`, so "at least half" is simply `>= 5_000`. The
+    /// computation uses integer arithmetic only, including for issued amounts.
+    ///
+    /// Both amounts must be of the same asset (see [`Amount::signed_sub`]) and non-negative.
+    /// Mixing assets, a negative amount or a zero `total` returns `Error::InvalidParams`.
+    pub fn percentage_of(&self, total: &Amount) -> host::Result<u32> {
+        let (part, whole, shift) = match (self, total) {
+            (Amount::XRP { num_drops: a }, Amount::XRP { num_drops: b }) if *a >= 0 && *b >= 0 => {
+                (*a as u128, *b as u128, 0)
+            }
+            (
+                Amount::MPT {
+                    num_units: a,
+                    is_positive: a_positive,
+                    mpt_id,
+                },
+                Amount::MPT {
+                    num_units: b,
+                    is_positive: b_positive,
+                    mpt_id: other_mpt_id,
+                },
+            ) if mpt_id == other_mpt_id && (*a_positive || *a == 0) && (*b_positive || *b == 0) => {
+                (*a as u128, *b as u128, 0)
+            }
+            (
+                Amount::IOU {
+                    amount: a,
+                    issuer,
+                    currency,
+                },
+                Amount::IOU {
+                    amount: b,
+                    issuer: other_issuer,
+                    currency: other_currency,
+                },
+            ) if issuer == other_issuer && currency == other_currency => {
+                match (iou_magnitude(a), iou_magnitude(b)) {
+                    (Some((a, a_exponent)), Some((b, b_exponent))) => {
+                        (a as u128, b as u128, a_exponent - b_exponent)
+                    }
+                    _ => return host::Result::Err(host::Error::InvalidParams),
+                }
+            }
+            _ => return host::Result::Err(host::Error::InvalidParams),
+        };
+        if whole == 0 {
+            return host::Result::Err(host::Error::InvalidParams);
+        }
+        if part == 0 {
+            return host::Result::Ok(0);
+        }
 
-        // Copy the mantissa bytes to the input array, preserving the exponent bits in input[1]
-        // The mantissa starts from the last 6 bits of input[1], then goes for 6 more bytes.
-        input[1] |= mantissa_bytes[0] & 0x3F; // Keep first 2 bits for exponent, set last 6 bits from mantissa
-        input[2] = mantissa_bytes[1];
-        input[3] = mantissa_bytes[2];
-        input[4] = mantissa_bytes[3];
-        input[5] = mantissa_bytes[4];
-        input[6] = mantissa_bytes[5];
-        input[7] = mantissa_bytes[6];
-        // input[8] = mantissa_bytes[7]; // <-- Not necessary.
+        // Canonical mantissas are below 10^16, so these bounds keep every product within u128
+        // while covering every shift that can produce a result between 1 and u32::MAX.
+        let basis_points = match shift {
+            shift if shift > 20 => None,
+            shift if shift < -22 => Some(0),
+            shift if shift >= 0 => 10u128
+                .checked_pow(shift as u32)
+                .and_then(|scale| (part * 10_000).checked_mul(scale))
+                .map(|scaled| scaled / whole),
+            shift => Some(part * 10_000 / (whole * 10u128.pow(shift.unsigned_abs()))),
+        };
+        match basis_points {
+            Some(basis_points) => host::Result::Ok(u32::try_from(basis_points).unwrap_or(u32::MAX)),
+            None => host::Result::Ok(u32::MAX),
+        }
+    }
+}
 
-        let mut eight_input_bytes: [u8; 8] = [0u8; 8];
-        eight_input_bytes.copy_from_slice(&input[..8]);
+/// Returns the canonical mantissa and exponent of a non-negative issued-amount float, or `None`
+/// if it is negative or cannot be represented. Zero has a mantissa of 0.
+fn iou_magnitude(float: &OpaqueFloat) -> Option<(u64, i32)> {
+    let bits = match canonicalize_iou(float) {
+        host::Result::Ok(canonical) => u64::from_be_bytes(canonical.0),
+        host::Result::Err(_) => return None,
+    };
+    let mantissa = bits & IOU_MANTISSA_MASK;
+    if mantissa != 0 && bits & 0x4000000000000000 == 0 {
+        return None;
+    }
+    Some((mantissa, ((bits >> 54) & 0xFF) as i32 - IOU_EXPONENT_BIAS))
+}
 
-        /////////////////
-        // Add the rest of the Amount Fields
-        /////////////////
+/// Writes the exact decimal value of an issued-amount float, using integer arithmetic only.
+///
+/// Following rippled's `STAmount::getText`, values whose canonical exponent is within
+/// `-25..=-5` are written in plain decimal notation (`"1234.5"`, `"-0.001"`); anything else uses
+/// scientific notation with an integer mantissa (`"1e20"`, `"-15e-16"`). Trailing zeros are
+/// dropped, and zero is always written as `"0"`.
+///
+/// Returns `core::fmt::Error` if the float is too large to represent canonically.
+pub(crate) fn write_iou_decimal<W: core::fmt::Write>(
+    float: &OpaqueFloat,
+    out: &mut W,
+) -> core::fmt::Result {
+    let bits = match canonicalize_iou(float) {
+        host::Result::Ok(canonical) => u64::from_be_bytes(canonical.0),
+        host::Result::Err(_) => return Err(core::fmt::Error),
+    };
+    let mut mantissa = bits & IOU_MANTISSA_MASK;
+    if mantissa == 0 {
+        return out.write_str("0");
+    }
+    let exponent = ((bits >> 54) & 0xFF) as i32 - IOU_EXPONENT_BIAS;
+    if bits & 0x4000000000000000 == 0 {
+        out.write_str("-")?;
+    }
 
-        // Create a test IOU amount byte array
-        // IOU amount: [1/type][1/sign][8/exponent][54/mantissa][160/currency][160/issuer]
-        // First byte: 0b1100_0000 (0xC0)
+    let mut digits = [0u8; 16];
+    for digit in digits.iter_mut().rev() {
+        *digit = b'0' + (mantissa % 10) as u8;
+        mantissa /= 10;
+    }
+    let significant = digits.iter().rposition(|d| *d != b'0').map_or(1, |i| i + 1);
+    // Only ASCII digits are ever written into `digits`.
+    let digits = unsafe { core::str::from_utf8_unchecked(&digits) };
 
-        let mut bytes = [0u8; 48];
+    if !(-25..=-5).contains(&exponent) {
+        let shift = (digits.len() - significant) as i32;
+        return write!(out, "{}e{}", &digits[..significant], exponent + shift);
+    }
 
-        bytes[0..8].copy_from_slice(&eight_input_bytes[0..8]);
+    // Position of the decimal point relative to the first digit; at most 11 in this range.
+    let point = digits.len() as i32 + exponent;
+    if point <= 0 {
+        out.write_str("0.")?;
+        for _ in 0..-point {
+            out.write_str("0")?;
+        }
+        out.write_str(&digits[..significant])
+    } else {
+        let point = point as usize;
+        out.write_str(&digits[..point])?;
+        if significant > point {
+            out.write_str(".")?;
+            out.write_str(&digits[point..significant])?;
+        }
+        Ok(())
+    }
+}
 
-        // Set the currency code bytes
-        const CURRENCY_BYTES: [u8; 20] = [2u8; 20]; // 20 bytes
-        bytes[8..28].copy_from_slice(&CURRENCY_BYTES);
+/// An [`Amount`] wrapper with a total order, for sorting collections of the same asset.
+///
+/// `Amount` itself does not implement `Ord` because amounts of different assets (XRP against
+/// USD, or USD from two different issuers) have no meaningful order. `SortableAmount` orders
+/// amounts by numeric value and is only valid for collections that all hold the same asset:
+/// XRP, issued amounts with the same currency and issuer, or MPT amounts with the same issuance.
+///
+/// # Panics
+///
+/// Comparing two `SortableAmount`s of different assets is a programming error and panics.
+/// Comparing an issued amount whose value cannot be represented canonically also panics; such
+/// values never come from the ledger.
+///
+/// # Example
+///
+/// ```
+/// use xrpl_wasm_stdlib::core::types::amount::{Amount, SortableAmount};
+///
+/// let mut offers = [
+///     SortableAmount(Amount::XRP { num_drops: 300 }),
+///     SortableAmount(Amount::XRP { num_drops: -5 }),
+///     SortableAmount(Amount::XRP { num_drops: 20 }),
+/// ];
+/// offers.sort_unstable();
+/// assert_eq!(offers[0].0, Amount::XRP { num_drops: -5 });
+/// ```
+#[derive(Debug, Clone)]
+pub struct SortableAmount(pub Amount);
+
+impl Ord for SortableAmount {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        match (&self.0, &other.0) {
+            (Amount::XRP { num_drops: a }, Amount::XRP { num_drops: b }) => a.cmp(b),
+            (
+                Amount::IOU {
+                    amount: a,
+                    issuer: a_issuer,
+                    currency: a_currency,
+                },
+                Amount::IOU {
+                    amount: b,
+                    issuer: b_issuer,
+                    currency: b_currency,
+                },
+            ) if a_issuer == b_issuer && a_currency == b_currency => {
+                iou_sort_key(a).cmp(&iou_sort_key(b))
+            }
+            (
+                Amount::MPT {
+                    num_units: a,
+                    is_positive: a_positive,
+                    mpt_id: a_id,
+                },
+                Amount::MPT {
+                    num_units: b,
+                    is_positive: b_positive,
+                    mpt_id: b_id,
+                },
+            ) if a_id == b_id => {
+                let a = if *a_positive {
+                    *a as i128
+                } else {
+                    -(*a as i128)
+                };
+                let b = if *b_positive {
+                    *b as i128
+                } else {
+                    -(*b as i128)
+                };
+                a.cmp(&b)
+            }
+            _ => panic!("SortableAmount compared across different assets"),
+        }
+    }
+}
+
+impl PartialOrd for SortableAmount {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Equal when the values are numerically equal, even if the issued-amount encodings differ.
+impl PartialEq for SortableAmount {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == core::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for SortableAmount {}
+
+/// Maps an issued-amount float to an integer that sorts in the same order as its value.
+///
+/// Canonical non-zero values all have mantissas of the same number of digits, so ordering by
+/// `(exponent, mantissa)` orders their magnitudes; negative values invert that order.
+fn iou_sort_key(float: &OpaqueFloat) -> i128 {
+    let bits = match canonicalize_iou(float) {
+        host::Result::Ok(canonical) => u64::from_be_bytes(canonical.0),
+        host::Result::Err(_) => panic!("SortableAmount compared a non-canonical issued amount"),
+    };
+    let mantissa = bits & IOU_MANTISSA_MASK;
+    if mantissa == 0 {
+        return 0;
+    }
+    let exponent = ((bits >> 54) & 0xFF) as i128;
+    let magnitude = (exponent << 64) | mantissa as i128;
+    if bits & 0x4000000000000000 != 0 {
+        magnitude
+    } else {
+        -magnitude
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Amount {
+    /// Parses a human-readable amount, such as a threshold written in a configuration file.
+    ///
+    /// Two forms are accepted:
+    /// - `"<value>"`: an XRP amount denominated in XRP (not drops), e.g. `"1.5"` or `"-0.000001"`.
+    ///   At most six decimal places may be non-zero.
+    /// - `"<value>/<currency>/<issuer>"`: an issued amount, e.g.
+    ///   `"100/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"`. The currency is a 3-character code or 40
+    ///   hex characters, and the issuer is a classic address or an AccountID in 40 hex characters.
+    ///
+    /// Both forms are also what [`Amount`]'s `Display` impl writes, so a displayed XRP or issued
+    /// amount parses back to an equal amount.
+    ///
+    /// Values are written in decimal with an optional sign, fractional part and exponent
+    /// (`"-1.25e-3"`), and are parsed with integer arithmetic only. Issued values are normalized
+    /// to canonical form, truncating digits beyond the 16 significant digits an issued amount
+    /// can hold.
+    ///
+    /// This is intended for host-side tooling and is not available when compiling to WASM.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidParams` - The input is not one of the two forms above
+    /// * `Error::InvalidFloatInput` - The value is malformed or out of range
+    /// * `Error::InvalidDecoding` - The currency is malformed or cannot be issued (e.g. `"XRP"`)
+    /// * `Error::InvalidAccount` - The issuer is neither a valid classic address nor 40 hex
+    ///   characters
+    pub fn parse_human(input: &str) -> host::Result<Amount> {
+        let mut parts = input.split('/');
+        let value = parts.next().unwrap_or_default();
+        match (parts.next(), parts.next(), parts.next()) {
+            (None, _, _) => match parse_xrp_drops(value) {
+                host::Result::Ok(num_drops) => host::Result::Ok(Amount::XRP { num_drops }),
+                host::Result::Err(e) => host::Result::Err(e),
+            },
+            (Some(currency), Some(issuer), None) => match parse_issuer(issuer) {
+                host::Result::Ok(issuer) => Amount::parse_issued(value, currency, issuer),
+                host::Result::Err(e) => host::Result::Err(e),
+            },
+            _ => host::Result::Err(host::Error::InvalidParams),
+        }
+    }
+
+    /// Builds an issued amount from a decimal `value` and a `currency` code (3 characters or 40
+    /// hex characters), with the same rules and errors as [`Amount::parse_human`].
+    pub(crate) fn parse_issued(
+        value: &str,
+        currency: &str,
+        issuer: AccountID,
+    ) -> host::Result<Amount> {
+        let (is_negative, mantissa, exponent, _) = match parse_decimal(value) {
+            host::Result::Ok(parsed) => parsed,
+            host::Result::Err(e) => return host::Result::Err(e),
+        };
+        let amount = match canonicalize_iou_parts(!is_negative, mantissa, exponent) {
+            host::Result::Ok(amount) => amount,
+            host::Result::Err(e) => return host::Result::Err(e),
+        };
+        match parse_currency(currency) {
+            host::Result::Ok(currency) => Amount::from_issued(amount, issuer, currency),
+            host::Result::Err(e) => host::Result::Err(e),
+        }
+    }
+}
+
+/// Parses a decimal string into `(is_negative, mantissa, exponent, is_exact)`, where the value is
+/// `mantissa * 10^exponent`.
+///
+/// The mantissa keeps the first 19 significant digits; `is_exact` is false if a non-zero digit
+/// beyond those had to be dropped.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_decimal(value: &str) -> host::Result<(bool, u64, i32, bool)> {
+    const MANTISSA_LIMIT: u64 = 1_000_000_000_000_000_000;
+
+    let (is_negative, rest) = match value.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, value),
+    };
+    let (number, exponent_digits) = match rest.split_once(['e', 'E']) {
+        Some((number, exponent_digits)) => (number, Some(exponent_digits)),
+        None => (rest, None),
+    };
+    let (integer_digits, fraction_digits) = match number.split_once('.') {
+        Some((integer_digits, fraction_digits)) => (integer_digits, Some(fraction_digits)),
+        None => (number, None),
+    };
+    let all_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    if !all_digits(integer_digits) || fraction_digits.is_some_and(|f| !all_digits(f)) {
+        return host::Result::Err(host::Error::InvalidFloatInput);
+    }
+
+    let mut mantissa: u64 = 0;
+    let mut exponent: i32 = 0;
+    let mut is_exact = true;
+    for digit in integer_digits.bytes() {
+        if mantissa < MANTISSA_LIMIT {
+            mantissa = mantissa * 10 + (digit - b'0') as u64;
+        } else {
+            is_exact &= digit == b'0';
+            exponent += 1;
+        }
+    }
+    for digit in fraction_digits.unwrap_or_default().bytes() {
+        if mantissa < MANTISSA_LIMIT {
+            mantissa = mantissa * 10 + (digit - b'0') as u64;
+            exponent -= 1;
+        } else {
+            is_exact &= digit == b'0';
+        }
+    }
+
+    if let Some(exponent_digits) = exponent_digits {
+        let (is_exponent_negative, exponent_digits) = match exponent_digits.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (
+                false,
+                exponent_digits.strip_prefix('+').unwrap_or(exponent_digits),
+            ),
+        };
+        if !all_digits(exponent_digits) {
+            return host::Result::Err(host::Error::InvalidFloatInput);
+        }
+        let mut explicit: i32 = 0;
+        for digit in exponent_digits.bytes() {
+            explicit = match explicit
+                .checked_mul(10)
+                .and_then(|e| e.checked_add((digit - b'0') as i32))
+            {
+                Some(e) => e,
+                None => return host::Result::Err(host::Error::InvalidFloatInput),
+            };
+        }
+        let explicit = if is_exponent_negative {
+            -explicit
+        } else {
+            explicit
+        };
+        exponent = match exponent.checked_add(explicit) {
+            Some(e) => e,
+            None => return host::Result::Err(host::Error::InvalidFloatInput),
+        };
+    }
+
+    host::Result::Ok((is_negative, mantissa, exponent, is_exact))
+}
+
+/// Parses an XRP value denominated in XRP into drops.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_xrp_drops(value: &str) -> host::Result<i64> {
+    let (is_negative, mut mantissa, mut exponent, is_exact) = match parse_decimal(value) {
+        host::Result::Ok(parsed) => parsed,
+        host::Result::Err(e) => return host::Result::Err(e),
+    };
+    if !is_exact {
+        return host::Result::Err(host::Error::InvalidFloatInput);
+    }
+
+    // Shift from XRP to drops, allowing trailing zeros beyond the sixth decimal place.
+    exponent = exponent.saturating_add(6);
+    while exponent < 0 && mantissa % 10 == 0 {
+        mantissa /= 10;
+        exponent += 1;
+    }
+    let drops = if mantissa == 0 {
+        Some(0)
+    } else if exponent < 0 {
+        None
+    } else {
+        u32::try_from(exponent)
+            .ok()
+            .and_then(|e| 10u64.checked_pow(e))
+            .and_then(|scale| mantissa.checked_mul(scale))
+            .filter(|drops| *drops <= MAX_DROPS)
+            .map(|drops| drops as i64)
+    };
+    match drops {
+        Some(drops) if is_negative => host::Result::Ok(-drops),
+        Some(drops) => host::Result::Ok(drops),
+        None => host::Result::Err(host::Error::InvalidFloatInput),
+    }
+}
+
+/// Parses an issuer written as a classic address or as an AccountID in 40 hex characters.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_issuer(issuer: &str) -> host::Result<AccountID> {
+    match <&[u8; 2 * ACCOUNT_ID_SIZE]>::try_from(issuer.as_bytes()) {
+        Ok(hex) => match crate::decode_hex_20(hex) {
+            Some(bytes) => host::Result::Ok(AccountID::from(bytes)),
+            None => host::Result::Err(host::Error::InvalidAccount),
+        },
+        Err(_) => AccountID::from_classic_address(issuer),
+    }
+}
+
+/// Parses a 3-character or 40-hex-character currency code that can be issued.
+#[cfg(not(target_arch = "wasm32"))]
+fn parse_currency(code: &str) -> host::Result<Currency> {
+    let currency = if let Ok(standard) = <[u8; STANDARD_CURRENCY_SIZE]>::try_from(code.as_bytes()) {
+        Currency::from(standard)
+    } else if let Ok(hex) = <&[u8; 2 * CURRENCY_SIZE]>::try_from(code.as_bytes()) {
+        match crate::decode_hex_20(hex) {
+            Some(bytes) => Currency::from(bytes),
+            None => return host::Result::Err(host::Error::InvalidDecoding),
+        }
+    } else {
+        return host::Result::Err(host::Error::InvalidDecoding);
+    };
+    if !currency.is_valid_issued() {
+        return host::Result::Err(host::Error::InvalidDecoding);
+    }
+    host::Result::Ok(currency)
+}
+
+/// Adds two amounts of the same asset.
+///
+/// This is a convenience over [`Amount::checked_add`] for code that knows its operands are
+/// compatible, typically XRP with XRP. When `checked_add` would fail:
+///
+/// - **Debug builds** panic, for both an asset mismatch and an out-of-range result.
+/// - **Release builds** do not panic:
+///   - XRP saturates at `i64::MIN`/`i64::MAX` drops.
+///   - MPT saturates at a magnitude of `u64::MAX` units, keeping the sign of the true result.
+///   - IOU saturates at the largest representable issued amount (mantissa `10^16 - 1`,
+///     exponent 80) with the sign of the left operand.
+///   - Mismatched assets return the left operand unchanged.
+///
+/// Use the `checked_*` methods wherever the operands come from untrusted input.
+impl core::ops::Add for Amount {
+    type Output = Amount;
+
+    fn add(self, rhs: Amount) -> Amount {
+        self.combine_or_saturate(rhs, false)
+    }
+}
+
+/// Subtracts two amounts of the same asset, with the same debug-panic and release-saturate
+/// policy as [`Amount`]'s `Add` impl. Results below zero are negative amounts, as with
+/// [`Amount::signed_sub`], not saturation.
+impl core::ops::Sub for Amount {
+    type Output = Amount;
+
+    fn sub(self, rhs: Amount) -> Amount {
+        self.combine_or_saturate(rhs, true)
+    }
+}
+
+/// Flips the sign of an amount (see [`Amount::checked_neg`]).
+///
+/// The only failing input, XRP `i64::MIN` drops, panics in debug builds and saturates to
+/// `i64::MAX` drops in release builds.
+impl core::ops::Neg for Amount {
+    type Output = Amount;
+
+    fn neg(self) -> Amount {
+        match self.checked_neg() {
+            host::Result::Ok(amount) => amount,
+            host::Result::Err(_) => {
+                if cfg!(debug_assertions) {
+                    panic!("Amount negation out of range");
+                }
+                Amount::XRP {
+                    num_drops: i64::MAX,
+                }
+            }
+        }
+    }
+}
+
+/// Compares amounts field by field, except that two issued amounts of the same currency and
+/// issuer are equal whenever both are zero.
+///
+/// An issued float's value is zero whenever its mantissa is, whatever its positive bit (bit 62)
+/// and exponent hold. The canonical zero `0x8000000000000000` has the positive bit clear, so a
+/// zero with that bit set (`0xC000000000000000`, a non-canonical positive zero) or with stray
+/// exponent bits still equals it. Non-zero issued values are still compared bit for bit; use
+/// [`Amount::canonicalize`] first to compare values that may not be canonical.
+impl PartialEq for Amount {
+    fn eq(&self, other: &Amount) -> bool {
+        match (self, other) {
+            (Amount::XRP { num_drops: a }, Amount::XRP { num_drops: b }) => a == b,
+            (
+                Amount::IOU {
+                    amount: a,
+                    issuer,
+                    currency,
+                },
+                Amount::IOU {
+                    amount: b,
+                    issuer: other_issuer,
+                    currency: other_currency,
+                },
+            ) => {
+                issuer == other_issuer
+                    && currency == other_currency
+                    && (a == b || (is_iou_zero(a) && is_iou_zero(b)))
+            }
+            (
+                Amount::MPT {
+                    num_units: a,
+                    is_positive: a_positive,
+                    mpt_id,
+                },
+                Amount::MPT {
+                    num_units: b,
+                    is_positive: b_positive,
+                    mpt_id: other_mpt_id,
+                },
+            ) => a == b && a_positive == b_positive && mpt_id == other_mpt_id,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Amount {}
+
+/// Returns whether an issued-amount float is zero, regardless of its positive bit and exponent.
+fn is_iou_zero(float: &OpaqueFloat) -> bool {
+    u64::from_be_bytes(float.0) & IOU_MANTISSA_MASK == 0
+}
+
+/// Formats the amount as exact decimal text, using integer arithmetic only. XRP and issued
+/// amounts use the grammar of [`Amount::parse_human`], which reads them back unchanged:
+///
+/// - XRP: `"<value>"` in XRP (not drops), e.g. `"1.5"` or `"-0.000012"`
+/// - IOU: `"<value>/<currency>/<issuer>"`, where `<value>` is the exact decimal value (any zero,
+///   canonical or not, is `"0"`), `<currency>` is the 3-character code or 40 hex characters for
+///   non-standard codes, and `<issuer>` is the issuer's AccountID in 40 hex characters, since
+///   encoding a classic address needs SHA-256, which contracts do not link
+/// - MPT: `"<units> MPT"`
+///
+/// An issued value too large to represent canonically fails with `core::fmt::Error`.
+impl core::fmt::Display for Amount {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Amount::XRP { num_drops } => {
+                let sign = if *num_drops < 0 { "-" } else { "" };
+                let drops = num_drops.unsigned_abs();
+                let (whole, mut fraction) = (drops / DROPS_PER_XRP, drops % DROPS_PER_XRP);
+                if fraction == 0 {
+                    return write!(f, "{sign}{whole}");
+                }
+                let mut width = 6;
+                while fraction % 10 == 0 {
+                    fraction /= 10;
+                    width -= 1;
+                }
+                write!(f, "{sign}{whole}.{fraction:0width$}")
+            }
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                write_iou_decimal(amount, f)?;
+                match currency.to_iso() {
+                    Some(code) => write!(f, "/{code}/")?,
+                    None => {
+                        f.write_str("/")?;
+                        currency
+                            .as_bytes()
+                            .iter()
+                            .try_for_each(|b| write!(f, "{b:02X}"))?;
+                        f.write_str("/")?;
+                    }
+                }
+                write!(f, "{issuer}")
+            }
+            Amount::MPT {
+                num_units,
+                is_positive,
+                ..
+            } => {
+                let sign = if *is_positive || *num_units == 0 {
+                    ""
+                } else {
+                    "-"
+                };
+                write!(f, "{sign}{num_units} MPT")
+            }
+        }
+    }
+}
+
+/// Creates an XRP amount from a number of drops.
+///
+/// Returns `Error::InvalidParams` for more drops than the total XRP supply ([`MAX_DROPS`]).
+impl TryFrom<u64> for Amount {
+    type Error = host::Error;
+
+    fn try_from(num_drops: u64) -> Result<Self, Self::Error> {
+        if num_drops > MAX_DROPS {
+            return Err(host::Error::InvalidParams);
+        }
+        Ok(Amount::XRP {
+            num_drops: num_drops as i64,
+        })
+    }
+}
+
+/// Extracts the number of drops from an XRP amount.
+///
+/// Returns `Error::InvalidParams` for a negative XRP amount and for IOU and MPT amounts.
+impl TryFrom<Amount> for u64 {
+    type Error = host::Error;
+
+    fn try_from(amount: Amount) -> Result<Self, Self::Error> {
+        match amount {
+            Amount::XRP { num_drops } if num_drops >= 0 => Ok(num_drops as u64),
+            _ => Err(host::Error::InvalidParams),
+        }
+    }
+}
+
+impl From<[u8; AMOUNT_SIZE]> for Amount {
+    fn from(bytes: [u8; AMOUNT_SIZE]) -> Self {
+        // Use the existing from_bytes method with a slice reference
+        match Self::from_bytes(&bytes) {
+            Ok(amount) => amount,
+            Err(error) => {
+                let _ = trace_num("Error parsing amount", error.code() as i64);
+                panic!("Invalid Amount byte array");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::opaque_float::FLOAT_ONE;
+    use crate::core::types::opaque_float::OpaqueFloat;
+    use crate::core::types::test_fmt::{self, SliceWriter};
+    use crate::host::error_codes::{
+        INVALID_ACCOUNT, INVALID_DECODING, INVALID_FIELD, INVALID_FLOAT_INPUT, INVALID_PARAMS,
+    };
+
+    #[test]
+    fn test_parse_xrp_amount() {
+        // Create a test XRP amount byte array
+        // XRP amount: [0/type][1/sign][0/is-mpt][4/reserved][57/value]
+        // First byte: 0b0100_0000 (0x40)
+        // Value: 1,000,000 (0xF4240 in hex)
+        let mut bytes = [0u8; 48];
+        bytes[0] = 0x40; // XRP positive flag
+        bytes[1..8].copy_from_slice(&1_000_000u64.to_be_bytes()[1..8]);
+
+        // Parse the Amount
+        let amount = Amount::from_bytes(&bytes).unwrap();
+
+        // Verify it's an XRP amount with the correct value
+        match amount {
+            Amount::XRP { num_drops } => {
+                assert_eq!(num_drops, 1_000_000);
+            }
+            _ => panic!("Expected Amount::XRP"),
+        }
+    }
+
+    #[test]
+    fn test_parse_mpt_amount() {
+        // Create a test MPT amount byte array
+        // MPT amount: [0/type][1/sign][1/is-mpt][5/reserved][64/value][32/sequence][160/issuer]
+        // First byte: 0b0110_0000 (0x60)
+        const VALUE: u64 = 500_000; // 8 bytes
+        const SEQUENCE_NUM: u32 = 12345; // 4 bytes
+        const ISSUER_BYTES: [u8; 20] = [1u8; 20]; // 20 bytes
+
+        let mut bytes = [0u8; 48];
+
+        // Set the amount bytes
+        bytes[0] = 0x60; // MPT positive flag
+        bytes[1..9].copy_from_slice(&VALUE.to_be_bytes());
+
+        // Set the MptId bytes
+        bytes[9..13].copy_from_slice(&SEQUENCE_NUM.to_be_bytes());
+        // Set the Issuer bytes.
+        bytes[13..33].copy_from_slice(&ISSUER_BYTES);
+
+        // Parse the Amount
+        let amount = Amount::from_bytes(&bytes).unwrap();
+
+        // Verify it's an MPT amount with the correct values
+        match amount {
+            Amount::MPT {
+                num_units,
+                is_positive,
+                mpt_id,
+            } => {
+                assert_eq!(num_units, VALUE);
+                assert!(is_positive);
+                assert_eq!(mpt_id.get_sequence_num(), SEQUENCE_NUM);
+                assert_eq!(mpt_id.get_issuer(), AccountID::from(ISSUER_BYTES));
+            }
+            _ => panic!("Expected Amount::MPT"),
+        }
+    }
+
+    #[test]
+    fn test_parse_iou_amount() {
+        // IOU with exponent = 5, mantissa = 12345
+        const EXPONENT: u8 = 5; // 1 byte
+        const MANTISSA: u64 = 12345; // 57 bits (so need or 8 bytes)
+
+        // First byte: 0b1100_0000 (0xC0, flags for IOU positive)
+        // For exponent 5:
+        // - We need to set the last 6 bits of the first byte and first 2 bits of the second byte
+        // - 5 = 0b00000101, so we need 0b000001 in the last 6 bits of first byte
+        // - and 0b01 in the first 2 bits of second byte
+
+        // Create the input bytes
+        let mut input = [0u8; 9];
+        // Set the first byte: IOU positive flag (0xC0) with exponent bits
+        input[0] = 0xC0 | ((EXPONENT >> 2) & 0x3F); // 5 >> 2 = 1, so this is 0xC1
+
+        // Set the second byte: first 2 bits for exponent, rest will be part of mantissa
+        input[1] = (EXPONENT & 0x03) << 6; // 5 & 0x03 = 1, 1 << 6 = 0x40
+
+        let mantissa_bytes = MANTISSA.to_be_bytes();
+
+        // Copy the mantissa bytes to the input array, preserving the exponent bits in input[1]
+        // The mantissa starts from the last 6 bits of input[1], then goes for 6 more bytes.
+        input[1] |= mantissa_bytes[0] & 0x3F; // Keep first 2 bits for exponent, set last 6 bits from mantissa
+        input[2] = mantissa_bytes[1];
+        input[3] = mantissa_bytes[2];
+        input[4] = mantissa_bytes[3];
+        input[5] = mantissa_bytes[4];
+        input[6] = mantissa_bytes[5];
+        input[7] = mantissa_bytes[6];
+        // input[8] = mantissa_bytes[7]; // <-- Not necessary.
+
+        let mut eight_input_bytes: [u8; 8] = [0u8; 8];
+        eight_input_bytes.copy_from_slice(&input[..8]);
+
+        /////////////////
+        // Add the rest of the Amount Fields
+        /////////////////
+
+        // Create a test IOU amount byte array
+        // IOU amount: [1/type][1/sign][8/exponent][54/mantissa][160/currency][160/issuer]
+        // First byte: 0b1100_0000 (0xC0)
+
+        let mut bytes = [0u8; 48];
+
+        bytes[0..8].copy_from_slice(&eight_input_bytes[0..8]);
+
+        // Set the currency code bytes
+        const CURRENCY_BYTES: [u8; 20] = [2u8; 20]; // 20 bytes
+        bytes[8..28].copy_from_slice(&CURRENCY_BYTES);
+
+        // Set the issuer bytes
+        const ISSUER_BYTES: [u8; 20] = [3u8; 20]; // 20 bytes
+        bytes[28..48].copy_from_slice(&ISSUER_BYTES);
+
+        // Parse the Amount
+        let amount = Amount::from_bytes(&bytes).unwrap();
+
+        // Verify it's an IOU amount with the correct values
+        match amount {
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                assert_eq!(amount, OpaqueFloat(eight_input_bytes));
+                assert_eq!(issuer, AccountID::from(ISSUER_BYTES));
+                assert_eq!(currency, Currency::from(CURRENCY_BYTES));
+            }
+            _ => panic!("Expected Amount::IOU"),
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_amount() {
+        // Test with an empty byte array
+        assert!(Amount::from_bytes(&[]).is_err());
+
+        // Test with a byte array that's too short for XRP
+        assert!(Amount::from_bytes(&[0x40, 0, 0]).is_err());
+
+        // Test with a byte array that's too short for MPT
+        let mut mpt_bytes = [0u8; 20];
+        mpt_bytes[0] = 0x60; // MPT positive flag
+        assert!(Amount::from_bytes(&mpt_bytes).is_err());
+
+        // Test with a byte array that's too short for IOU
+        let mut iou_bytes = [0u8; 30];
+        iou_bytes[0] = 0xC0; // IOU positive flag
+        assert!(Amount::from_bytes(&iou_bytes).is_err());
+
+        // Test with an invalid type bit pattern
+        assert!(Amount::from_bytes(&[0xA0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_xrp_positive() {
+        // Test positive XRP amount
+        let original = Amount::XRP {
+            num_drops: 1_000_000,
+        };
+
+        // Create the expected byte layout for XRP
+        // XRP format: [0/type][1/sign][0/is-mpt][4/reserved][57/value]
+        let mut expected_bytes = [0u8; 48];
+        expected_bytes[0] = 0x40; // Positive XRP flag (0b0100_0000)
+        expected_bytes[1..8].copy_from_slice(&1_000_000u64.to_be_bytes()[1..8]);
+
+        // Test from_bytes -> to_bytes round trip
+        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
+        assert_eq!(parsed, original);
+
+        // Test to_stamount_bytes format (should include sign bit for positive)
+        let (stamount_bytes, len) = original.to_stamount_bytes();
+        assert_eq!(len, 48);
+        let expected_value = 1_000_000u64 | 0x4000000000000000u64; // Add positive sign bit
+        assert_eq!(&stamount_bytes[0..8], &expected_value.to_be_bytes());
+        // Remaining bytes should be zero padding
+        assert_eq!(&stamount_bytes[8..48], &[0u8; 40]);
+    }
+
+    #[test]
+    fn test_round_trip_xrp_negative() {
+        // Test negative XRP amount
+        let original = Amount::XRP {
+            num_drops: -500_000,
+        };
+
+        // Create the expected byte layout for negative XRP
+        // XRP format: [0/type][0/sign][0/is-mpt][4/reserved][57/value]
+        let mut expected_bytes = [0u8; 48];
+        expected_bytes[0] = 0x00; // Negative XRP flag (0b0000_0000)
+        expected_bytes[1..8].copy_from_slice(&500_000u64.to_be_bytes()[1..8]);
+
+        // Test from_bytes -> to_bytes round trip
+        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
+        assert_eq!(parsed, original);
+
+        // Test to_stamount_bytes format (should NOT include sign bit for negative)
+        let (stamount_bytes, len) = original.to_stamount_bytes();
+        assert_eq!(len, 48);
+        assert_eq!(&stamount_bytes[0..8], &500_000u64.to_be_bytes());
+        // Remaining bytes should be zero padding
+        assert_eq!(&stamount_bytes[8..48], &[0u8; 40]);
+    }
+
+    #[test]
+    fn test_round_trip_mpt_positive() {
+        // Test positive MPT amount
+        const VALUE: u64 = 750_000;
+        const SEQUENCE_NUM: u32 = 54321;
+        const ISSUER_BYTES: [u8; 20] = [0xAB; 20];
+
+        let issuer = AccountID::from(ISSUER_BYTES);
+        let mpt_id = MptId::new(SEQUENCE_NUM, issuer);
+        let original = Amount::MPT {
+            num_units: VALUE,
+            is_positive: true,
+            mpt_id,
+        };
+
+        // Create the expected byte layout for positive MPT
+        // MPT format: [0/type][1/sign][1/is-mpt][5/reserved][64/value][32/sequence][160/issuer]
+        let mut expected_bytes = [0u8; 48];
+        expected_bytes[0] = 0x60; // Positive MPT flag (0b0110_0000)
+        expected_bytes[1..9].copy_from_slice(&VALUE.to_be_bytes());
+        expected_bytes[9..13].copy_from_slice(&SEQUENCE_NUM.to_be_bytes());
+        expected_bytes[13..33].copy_from_slice(&ISSUER_BYTES);
+
+        // Test from_bytes -> to_bytes round trip
+        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
+        assert_eq!(parsed, original);
+
+        // Test to_stamount_bytes format
+        let (stamount_bytes, len) = original.to_stamount_bytes();
+        assert_eq!(len, 48);
+        assert_eq!(stamount_bytes[0], 0x60); // Flag byte
+        assert_eq!(&stamount_bytes[1..9], &VALUE.to_be_bytes()); // Amount
+        assert_eq!(&stamount_bytes[9..33], mpt_id.as_bytes()); // MptId
+        // Remaining bytes should be zero padding
+        assert_eq!(&stamount_bytes[33..48], &[0u8; 15]);
+    }
+
+    #[test]
+    fn test_round_trip_mpt_negative() {
+        // Test negative MPT amount
+        const VALUE: u64 = 250_000;
+        const SEQUENCE_NUM: u32 = 98765;
+        const ISSUER_BYTES: [u8; 20] = [0xCD; 20];
+
+        let issuer = AccountID::from(ISSUER_BYTES);
+        let mpt_id = MptId::new(SEQUENCE_NUM, issuer);
+        let original = Amount::MPT {
+            num_units: VALUE,
+            is_positive: false,
+            mpt_id,
+        };
+
+        // Create the expected byte layout for negative MPT
+        // MPT format: [0/type][0/sign][1/is-mpt][5/reserved][64/value][32/sequence][160/issuer]
+        let mut expected_bytes = [0u8; 48];
+        expected_bytes[0] = 0x20; // Negative MPT flag (0b0010_0000)
+        expected_bytes[1..9].copy_from_slice(&VALUE.to_be_bytes());
+        expected_bytes[9..13].copy_from_slice(&SEQUENCE_NUM.to_be_bytes());
+        expected_bytes[13..33].copy_from_slice(&ISSUER_BYTES);
+
+        // Test from_bytes -> to_bytes round trip
+        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
+        assert_eq!(parsed, original);
+
+        // Test to_stamount_bytes format
+        let (stamount_bytes, len) = original.to_stamount_bytes();
+        assert_eq!(len, 48);
+        assert_eq!(stamount_bytes[0], 0x20); // Flag byte (negative)
+        assert_eq!(&stamount_bytes[1..9], &VALUE.to_be_bytes()); // Amount
+        assert_eq!(&stamount_bytes[9..33], mpt_id.as_bytes()); // MptId
+        // Remaining bytes should be zero padding
+        assert_eq!(&stamount_bytes[33..48], &[0u8; 15]);
+    }
+
+    #[test]
+    fn test_round_trip_iou_positive() {
+        // Test positive IOU amount
+        const EXPONENT: u8 = 7;
+        const MANTISSA: u64 = 98765;
+        const CURRENCY_BYTES: [u8; 20] = [0xEF; 20];
+        const ISSUER_BYTES: [u8; 20] = [0x12; 20];
+
+        // Create the OpaqueFloat bytes manually
+        // IOU format: [1/type][1/sign][8/exponent][54/mantissa]
+        let mut opaque_float_bytes = [0u8; 8];
+
+        // First byte: IOU positive flag (0xC0) with exponent bits
+        opaque_float_bytes[0] = 0xC0 | ((EXPONENT >> 2) & 0x3F);
+
+        // Second byte: first 2 bits for exponent, rest will be part of mantissa
+        opaque_float_bytes[1] = (EXPONENT & 0x03) << 6;
+
+        let mantissa_bytes = MANTISSA.to_be_bytes();
+
+        // Copy the mantissa bytes, preserving the exponent bits in opaque_float_bytes[1]
+        opaque_float_bytes[1] |= mantissa_bytes[0] & 0x3F;
+        opaque_float_bytes[2] = mantissa_bytes[1];
+        opaque_float_bytes[3] = mantissa_bytes[2];
+        opaque_float_bytes[4] = mantissa_bytes[3];
+        opaque_float_bytes[5] = mantissa_bytes[4];
+        opaque_float_bytes[6] = mantissa_bytes[5];
+        opaque_float_bytes[7] = mantissa_bytes[6];
+
+        let original = Amount::IOU {
+            amount: OpaqueFloat(opaque_float_bytes),
+            issuer: AccountID::from(ISSUER_BYTES),
+            currency: Currency::from(CURRENCY_BYTES),
+        };
+
+        // Create the expected byte layout for IOU
+        // IOU format: [1/type][1/sign][8/exponent][54/mantissa][160/currency][160/issuer]
+        let mut expected_bytes = [0u8; 48];
+        expected_bytes[0..8].copy_from_slice(&opaque_float_bytes);
+        expected_bytes[8..28].copy_from_slice(&CURRENCY_BYTES);
+        expected_bytes[28..48].copy_from_slice(&ISSUER_BYTES);
+
+        // Test from_bytes -> to_bytes round trip
+        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
+        assert_eq!(parsed, original);
+
+        // Test to_stamount_bytes format
+        let (stamount_bytes, len) = original.to_stamount_bytes();
+        assert_eq!(len, 48);
+        assert_eq!(&stamount_bytes[0..8], &opaque_float_bytes); // OpaqueFloat
+        assert_eq!(&stamount_bytes[8..28], &CURRENCY_BYTES); // Currency
+        assert_eq!(&stamount_bytes[28..48], &ISSUER_BYTES); // Issuer
+        // No padding for IOU - uses all 48 bytes
+    }
+
+    #[test]
+    fn test_round_trip_iou_negative() {
+        // Test negative IOU amount
+        const EXPONENT: u8 = 3;
+        const MANTISSA: u64 = 12345;
+        const CURRENCY_BYTES: [u8; 20] = [0x34; 20];
+        const ISSUER_BYTES: [u8; 20] = [0x56; 20];
+
+        // Create the OpaqueFloat bytes manually for negative amount
+        // IOU format: [1/type][0/sign][8/exponent][54/mantissa]
+        let mut opaque_float_bytes = [0u8; 8];
+
+        // First byte: IOU negative flag (0x80) with exponent bits
+        opaque_float_bytes[0] = 0x80 | ((EXPONENT >> 2) & 0x3F);
+
+        // Second byte: first 2 bits for exponent, rest will be part of mantissa
+        opaque_float_bytes[1] = (EXPONENT & 0x03) << 6;
+
+        let mantissa_bytes = MANTISSA.to_be_bytes();
+
+        // Copy the mantissa bytes, preserving the exponent bits in opaque_float_bytes[1]
+        opaque_float_bytes[1] |= mantissa_bytes[0] & 0x3F;
+        opaque_float_bytes[2] = mantissa_bytes[1];
+        opaque_float_bytes[3] = mantissa_bytes[2];
+        opaque_float_bytes[4] = mantissa_bytes[3];
+        opaque_float_bytes[5] = mantissa_bytes[4];
+        opaque_float_bytes[6] = mantissa_bytes[5];
+        opaque_float_bytes[7] = mantissa_bytes[6];
+
+        let original = Amount::IOU {
+            amount: OpaqueFloat(opaque_float_bytes),
+            issuer: AccountID::from(ISSUER_BYTES),
+            currency: Currency::from(CURRENCY_BYTES),
+        };
+
+        // Create the expected byte layout for negative IOU
+        // IOU format: [1/type][0/sign][8/exponent][54/mantissa][160/currency][160/issuer]
+        let mut expected_bytes = [0u8; 48];
+        expected_bytes[0..8].copy_from_slice(&opaque_float_bytes);
+        expected_bytes[8..28].copy_from_slice(&CURRENCY_BYTES);
+        expected_bytes[28..48].copy_from_slice(&ISSUER_BYTES);
 
-        // Set the issuer bytes
-        const ISSUER_BYTES: [u8; 20] = [3u8; 20]; // 20 bytes
-        bytes[28..48].copy_from_slice(&ISSUER_BYTES);
+        // Test from_bytes -> to_bytes round trip
+        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
+        assert_eq!(parsed, original);
 
-        // Parse the Amount
-        let amount = Amount::from_bytes(&bytes).unwrap();
+        // Test to_stamount_bytes format
+        let (stamount_bytes, len) = original.to_stamount_bytes();
+        assert_eq!(len, 48);
+        assert_eq!(&stamount_bytes[0..8], &opaque_float_bytes); // OpaqueFloat
+        assert_eq!(&stamount_bytes[8..28], &CURRENCY_BYTES); // Currency
+        assert_eq!(&stamount_bytes[28..48], &ISSUER_BYTES); // Issuer
+        // No padding for IOU - uses all 48 bytes
+    }
 
-        // Verify it's an IOU amount with the correct values
-        match amount {
-            Amount::IOU {
-                amount,
-                issuer,
-                currency,
-            } => {
-                assert_eq!(amount, OpaqueFloat(eight_input_bytes));
-                assert_eq!(issuer, AccountID::from(ISSUER_BYTES));
-                assert_eq!(currency, Currency::from(CURRENCY_BYTES));
+    #[test]
+    fn test_round_trip_edge_cases() {
+        // Test XRP with maximum value that fits in 57 bits
+        let max_57_bit_value = MASK_57_BIT as i64;
+        let max_xrp = Amount::XRP {
+            num_drops: max_57_bit_value,
+        };
+        let mut max_xrp_bytes = [0u8; 48];
+
+        // Create the full 64-bit value with flag bits
+        let full_value = (max_57_bit_value as u64) | 0x4000000000000000u64; // Add positive flag
+        max_xrp_bytes[0..8].copy_from_slice(&full_value.to_be_bytes());
+
+        let parsed_max_xrp = Amount::from_bytes(&max_xrp_bytes).unwrap();
+        assert_eq!(parsed_max_xrp, max_xrp);
+
+        // Test XRP with maximum negative value that fits in 57 bits
+        let min_xrp = Amount::XRP {
+            num_drops: -max_57_bit_value,
+        };
+        let mut min_xrp_bytes = [0u8; 48];
+
+        // Create the full 64-bit value without positive flag (negative)
+        let full_value = max_57_bit_value as u64; // No positive flag = negative
+        min_xrp_bytes[0..8].copy_from_slice(&full_value.to_be_bytes());
+
+        let parsed_min_xrp = Amount::from_bytes(&min_xrp_bytes).unwrap();
+        assert_eq!(parsed_min_xrp, min_xrp);
+
+        // Test XRP with zero value
+        let zero_xrp = Amount::XRP { num_drops: 0 };
+        let mut zero_xrp_bytes = [0u8; 48];
+        zero_xrp_bytes[0] = 0x40; // Positive flag (zero is considered positive)
+
+        let parsed_zero_xrp = Amount::from_bytes(&zero_xrp_bytes).unwrap();
+        assert_eq!(parsed_zero_xrp, zero_xrp);
+
+        // Test that values larger than 57 bits get properly masked during parsing
+        let large_value = i64::MAX;
+        let expected_masked_value = (large_value as u64 & MASK_57_BIT) as i64;
+        let large_xrp = Amount::XRP {
+            num_drops: expected_masked_value,
+        };
+
+        let mut large_xrp_bytes = [0u8; 48];
+        // Create the full 64-bit value with XRP positive flag and the large value
+        let masked_value = (large_value as u64) & MASK_57_BIT;
+        let full_value = masked_value | 0x4000000000000000u64; // Add positive flag (bit 62)
+        large_xrp_bytes[0..8].copy_from_slice(&full_value.to_be_bytes());
+
+        let parsed_large_xrp = Amount::from_bytes(&large_xrp_bytes).unwrap();
+        assert_eq!(parsed_large_xrp, large_xrp);
+    }
+
+    #[test]
+    fn test_is_dust_xrp_boundary() {
+        let below = Amount::XRP { num_drops: 999 };
+        let exact = Amount::XRP { num_drops: 1000 };
+        let above = Amount::XRP { num_drops: 1001 };
+
+        assert!(below.is_dust(1000).unwrap());
+        assert!(!exact.is_dust(1000).unwrap());
+        assert!(!above.is_dust(1000).unwrap());
+    }
+
+    #[test]
+    fn test_is_dust_uses_magnitude() {
+        assert!(Amount::XRP { num_drops: -5 }.is_dust(10).unwrap());
+        assert!(!Amount::XRP { num_drops: -10 }.is_dust(10).unwrap());
+        assert!(!Amount::XRP { num_drops: 0 }.is_dust(0).unwrap());
+    }
+
+    #[test]
+    fn test_try_from_u64_drops() {
+        assert_eq!(
+            Amount::try_from(1_000_000u64).unwrap(),
+            Amount::XRP {
+                num_drops: 1_000_000
             }
-            _ => panic!("Expected Amount::IOU"),
-        }
+        );
+        assert_eq!(
+            Amount::try_from(MAX_DROPS).unwrap(),
+            Amount::XRP {
+                num_drops: MAX_DROPS as i64
+            }
+        );
+        assert_eq!(
+            Amount::try_from(MAX_DROPS + 1).err().unwrap().code(),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            Amount::try_from(u64::MAX).err().unwrap().code(),
+            INVALID_PARAMS
+        );
     }
 
     #[test]
-    fn test_parse_invalid_amount() {
-        // Test with an empty byte array
-        assert!(Amount::from_bytes(&[]).is_err());
+    fn test_try_into_u64_drops() {
+        assert_eq!(u64::try_from(Amount::XRP { num_drops: 12 }).unwrap(), 12);
+        assert_eq!(u64::try_from(Amount::XRP { num_drops: 0 }).unwrap(), 0);
+        assert_eq!(
+            u64::try_from(Amount::XRP { num_drops: -1 })
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+        let mpt = Amount::MPT {
+            num_units: 5,
+            is_positive: true,
+            mpt_id: MptId::from([0u8; 24]),
+        };
+        assert_eq!(u64::try_from(mpt).err().unwrap().code(), INVALID_PARAMS);
+        let iou = Amount::IOU {
+            amount: OpaqueFloat(IOU_ZERO.to_be_bytes()),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert_eq!(u64::try_from(iou).err().unwrap().code(), INVALID_PARAMS);
+    }
 
-        // Test with a byte array that's too short for XRP
-        assert!(Amount::from_bytes(&[0x40, 0, 0]).is_err());
+    #[test]
+    fn test_cmp_drops() {
+        use core::cmp::Ordering;
+
+        let amount = Amount::XRP { num_drops: 1000 };
+        assert_eq!(amount.cmp_drops(1000).unwrap(), Ordering::Equal);
+        assert_eq!(amount.cmp_drops(1001).unwrap(), Ordering::Less);
+        assert_eq!(amount.cmp_drops(999).unwrap(), Ordering::Greater);
+        assert_eq!(
+            Amount::XRP { num_drops: -1 }.cmp_drops(0).unwrap(),
+            Ordering::Less
+        );
+        assert_eq!(
+            Amount::XRP {
+                num_drops: i64::MAX
+            }
+            .cmp_drops(u64::MAX)
+            .unwrap(),
+            Ordering::Less
+        );
+    }
 
-        // Test with a byte array that's too short for MPT
-        let mut mpt_bytes = [0u8; 20];
-        mpt_bytes[0] = 0x60; // MPT positive flag
-        assert!(Amount::from_bytes(&mpt_bytes).is_err());
+    #[test]
+    fn test_cmp_drops_rejects_non_xrp() {
+        let mpt = Amount::MPT {
+            num_units: 1,
+            is_positive: true,
+            mpt_id: MptId::from([0u8; 24]),
+        };
+        assert_eq!(mpt.cmp_drops(1).err().unwrap().code(), INVALID_PARAMS);
+    }
 
-        // Test with a byte array that's too short for IOU
-        let mut iou_bytes = [0u8; 30];
-        iou_bytes[0] = 0xC0; // IOU positive flag
-        assert!(Amount::from_bytes(&iou_bytes).is_err());
+    #[test]
+    fn test_is_dust_rejects_non_xrp() {
+        let iou = Amount::IOU {
+            amount: OpaqueFloat([0u8; 8]),
+            issuer: AccountID::from([0u8; 20]),
+            currency: Currency::from([0u8; 20]),
+        };
+        let mpt = Amount::MPT {
+            num_units: 1,
+            is_positive: true,
+            mpt_id: MptId::from([0u8; 24]),
+        };
 
-        // Test with an invalid type bit pattern
-        assert!(Amount::from_bytes(&[0xA0, 0, 0, 0, 0, 0, 0, 0]).is_err());
+        assert_eq!(iou.is_dust(1000).err().unwrap().code(), INVALID_PARAMS);
+        assert_eq!(mpt.is_dust(1000).err().unwrap().code(), INVALID_PARAMS);
     }
 
     #[test]
-    fn test_round_trip_xrp_positive() {
-        // Test positive XRP amount
-        let original = Amount::XRP {
-            num_drops: 1_000_000,
+    fn test_checked_add_and_neg() {
+        let mpt_id = MptId::new(1, AccountID::from([7u8; 20]));
+        let mpt = |num_units: u64, is_positive: bool| Amount::MPT {
+            num_units,
+            is_positive,
+            mpt_id,
         };
 
-        // Create the expected byte layout for XRP
-        // XRP format: [0/type][1/sign][0/is-mpt][4/reserved][57/value]
-        let mut expected_bytes = [0u8; 48];
-        expected_bytes[0] = 0x40; // Positive XRP flag (0b0100_0000)
-        expected_bytes[1..8].copy_from_slice(&1_000_000u64.to_be_bytes()[1..8]);
+        assert_eq!(
+            Amount::XRP { num_drops: 100 }
+                .checked_add(&Amount::XRP { num_drops: -30 })
+                .unwrap(),
+            Amount::XRP { num_drops: 70 }
+        );
+        assert_eq!(
+            mpt(10, true).checked_add(&mpt(25, false)).unwrap(),
+            mpt(15, false)
+        );
+        assert_eq!(
+            Amount::XRP {
+                num_drops: i64::MAX
+            }
+            .checked_add(&Amount::XRP { num_drops: 1 })
+            .err()
+            .unwrap()
+            .code(),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            Amount::XRP { num_drops: 1 }
+                .checked_add(&mpt(1, true))
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+
+        assert_eq!(mpt(5, true).checked_neg().unwrap(), mpt(5, false));
+        assert_eq!(mpt(0, true).checked_neg().unwrap(), mpt(0, true));
+        assert!(
+            Amount::XRP {
+                num_drops: i64::MIN
+            }
+            .checked_neg()
+            .is_err()
+        );
+    }
 
-        // Test from_bytes -> to_bytes round trip
-        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
-        assert_eq!(parsed, original);
+    #[test]
+    fn test_checked_neg_iou_flips_sign_bit() {
+        let iou = |bits: u64| Amount::IOU {
+            amount: OpaqueFloat(bits.to_be_bytes()),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        // 1 and -1
+        assert_eq!(
+            iou(0xD4838D7EA4C68000).checked_neg().unwrap(),
+            iou(0x94838D7EA4C68000)
+        );
+        assert_eq!(
+            iou(0x94838D7EA4C68000).checked_neg().unwrap(),
+            iou(0xD4838D7EA4C68000)
+        );
+        assert_eq!(iou(IOU_ZERO).checked_neg().unwrap(), iou(IOU_ZERO));
+    }
 
-        // Test to_stamount_bytes format (should include sign bit for positive)
-        let (stamount_bytes, len) = original.to_stamount_bytes();
-        assert_eq!(len, 48);
-        let expected_value = 1_000_000u64 | 0x4000000000000000u64; // Add positive sign bit
-        assert_eq!(&stamount_bytes[0..8], &expected_value.to_be_bytes());
-        // Remaining bytes should be zero padding
-        assert_eq!(&stamount_bytes[8..48], &[0u8; 40]);
+    #[test]
+    fn test_operators_on_xrp() {
+        let total = Amount::XRP { num_drops: 100 } + Amount::XRP { num_drops: 50 }
+            - Amount::XRP { num_drops: 200 };
+        assert_eq!(total, Amount::XRP { num_drops: -50 });
+        assert_eq!(-total, Amount::XRP { num_drops: 50 });
+    }
+
+    fn mpt_one() -> Amount {
+        Amount::MPT {
+            num_units: 1,
+            is_positive: true,
+            mpt_id: MptId::new(1, AccountID::from([7u8; 20])),
+        }
     }
 
     #[test]
-    fn test_round_trip_xrp_negative() {
-        // Test negative XRP amount
-        let original = Amount::XRP {
-            num_drops: -500_000,
+    #[cfg_attr(not(debug_assertions), ignore = "operators saturate in release builds")]
+    #[should_panic(expected = "different assets or out of range")]
+    fn test_add_operator_panics_on_asset_mismatch_in_debug() {
+        let _ = Amount::XRP { num_drops: 1 } + mpt_one();
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "operators saturate in release builds")]
+    #[should_panic(expected = "different assets or out of range")]
+    fn test_sub_operator_panics_on_asset_mismatch_in_debug() {
+        let _ = Amount::XRP { num_drops: 1 } - mpt_one();
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "operators saturate in release builds")]
+    #[should_panic(expected = "different assets or out of range")]
+    fn test_add_operator_panics_on_overflow_in_debug() {
+        let _ = Amount::XRP {
+            num_drops: i64::MAX,
+        } + Amount::XRP { num_drops: 1 };
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "operators saturate in release builds")]
+    #[should_panic(expected = "different assets or out of range")]
+    fn test_sub_operator_panics_on_overflow_in_debug() {
+        let _ = Amount::XRP {
+            num_drops: i64::MIN,
+        } - Amount::XRP { num_drops: 1 };
+    }
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "operators saturate in release builds")]
+    #[should_panic(expected = "negation out of range")]
+    fn test_neg_operator_panics_on_overflow_in_debug() {
+        let _ = -Amount::XRP {
+            num_drops: i64::MIN,
         };
+    }
 
-        // Create the expected byte layout for negative XRP
-        // XRP format: [0/type][0/sign][0/is-mpt][4/reserved][57/value]
-        let mut expected_bytes = [0u8; 48];
-        expected_bytes[0] = 0x00; // Negative XRP flag (0b0000_0000)
-        expected_bytes[1..8].copy_from_slice(&500_000u64.to_be_bytes()[1..8]);
+    #[test]
+    fn test_checked_ops_reject_asset_mismatch() {
+        let xrp = Amount::XRP { num_drops: 1 };
+        let usd = Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        let eur = Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"EUR"),
+        };
+        for (a, b) in [
+            (&xrp, &mpt_one()),
+            (&mpt_one(), &xrp),
+            (&xrp, &usd),
+            (&usd, &eur),
+        ] {
+            assert_eq!(a.checked_add(b).err().unwrap().code(), INVALID_PARAMS);
+            assert_eq!(a.checked_sub(b).err().unwrap().code(), INVALID_PARAMS);
+        }
+    }
 
-        // Test from_bytes -> to_bytes round trip
-        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
-        assert_eq!(parsed, original);
+    #[test]
+    fn test_saturating_combine() {
+        let max = Amount::XRP {
+            num_drops: i64::MAX,
+        };
+        assert_eq!(
+            max.saturating_combine(&Amount::XRP { num_drops: 1 }, false),
+            max
+        );
+        assert_eq!(
+            Amount::XRP {
+                num_drops: i64::MIN
+            }
+            .saturating_combine(&Amount::XRP { num_drops: 1 }, true),
+            Amount::XRP {
+                num_drops: i64::MIN
+            }
+        );
 
-        // Test to_stamount_bytes format (should NOT include sign bit for negative)
-        let (stamount_bytes, len) = original.to_stamount_bytes();
-        assert_eq!(len, 48);
-        assert_eq!(&stamount_bytes[0..8], &500_000u64.to_be_bytes());
-        // Remaining bytes should be zero padding
-        assert_eq!(&stamount_bytes[8..48], &[0u8; 40]);
+        let mpt_id = MptId::new(1, AccountID::from([7u8; 20]));
+        let mpt = |num_units: u64, is_positive: bool| Amount::MPT {
+            num_units,
+            is_positive,
+            mpt_id,
+        };
+        assert_eq!(
+            mpt(u64::MAX, false).saturating_combine(&mpt(1, true), true),
+            mpt(u64::MAX, false)
+        );
+
+        let iou = Amount::IOU {
+            amount: OpaqueFloat(0x94838D7EA4C68000u64.to_be_bytes()),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        match iou.saturating_combine(&iou, false) {
+            Amount::IOU { amount, .. } => {
+                // The most negative issued amount: -9999999999999999e80.
+                assert_eq!(u64::from_be_bytes(amount.0), 0xAC6386F26FC0FFFF);
+            }
+            _ => panic!("expected an issued amount"),
+        }
+
+        // Mismatched assets leave the left operand unchanged.
+        assert_eq!(
+            max.saturating_combine(&mpt(1, true), false),
+            Amount::XRP {
+                num_drops: i64::MAX
+            }
+        );
     }
 
     #[test]
-    fn test_round_trip_mpt_positive() {
-        // Test positive MPT amount
-        const VALUE: u64 = 750_000;
-        const SEQUENCE_NUM: u32 = 54321;
-        const ISSUER_BYTES: [u8; 20] = [0xAB; 20];
+    fn test_split_even_keeps_remainder() {
+        let (each, remainder) = Amount::XRP {
+            num_drops: 1_000_003,
+        }
+        .split_even(4)
+        .unwrap();
+        assert_eq!(each, Amount::XRP { num_drops: 250_000 });
+        assert_eq!(remainder, Amount::XRP { num_drops: 3 });
+
+        let (each, remainder) = Amount::XRP { num_drops: -7 }.split_even(2).unwrap();
+        assert_eq!(each, Amount::XRP { num_drops: -3 });
+        assert_eq!(remainder, Amount::XRP { num_drops: -1 });
+
+        let mpt_id = MptId::new(1, AccountID::from([7u8; 20]));
+        let mpt = |num_units: u64| Amount::MPT {
+            num_units,
+            is_positive: true,
+            mpt_id,
+        };
+        assert_eq!(mpt(10).split_even(5).unwrap(), (mpt(2), mpt(0)));
+    }
 
-        let issuer = AccountID::from(ISSUER_BYTES);
-        let mpt_id = MptId::new(SEQUENCE_NUM, issuer);
-        let original = Amount::MPT {
-            num_units: VALUE,
+    #[test]
+    fn test_split_even_rejects_zero_and_iou() {
+        assert_eq!(
+            Amount::XRP { num_drops: 10 }
+                .split_even(0)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+        let iou = Amount::IOU {
+            amount: OpaqueFloat(0xD4838D7EA4C68000u64.to_be_bytes()),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert_eq!(iou.split_even(2).err().unwrap().code(), INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_sum_same_asset() {
+        let payouts = [
+            Amount::XRP { num_drops: 100 },
+            Amount::XRP { num_drops: 250 },
+            Amount::XRP { num_drops: -50 },
+        ];
+        assert_eq!(
+            Amount::sum(&payouts).unwrap(),
+            Amount::XRP { num_drops: 300 }
+        );
+        assert_eq!(
+            Amount::sum(&payouts[..1]).unwrap(),
+            Amount::XRP { num_drops: 100 }
+        );
+
+        let mpt_id = MptId::new(1, AccountID::from([7u8; 20]));
+        let mpt = |num_units: u64| Amount::MPT {
+            num_units,
             is_positive: true,
             mpt_id,
         };
+        assert_eq!(Amount::sum(&[mpt(1), mpt(2), mpt(3)]).unwrap(), mpt(6));
+    }
 
-        // Create the expected byte layout for positive MPT
-        // MPT format: [0/type][1/sign][1/is-mpt][5/reserved][64/value][32/sequence][160/issuer]
-        let mut expected_bytes = [0u8; 48];
-        expected_bytes[0] = 0x60; // Positive MPT flag (0b0110_0000)
-        expected_bytes[1..9].copy_from_slice(&VALUE.to_be_bytes());
-        expected_bytes[9..13].copy_from_slice(&SEQUENCE_NUM.to_be_bytes());
-        expected_bytes[13..33].copy_from_slice(&ISSUER_BYTES);
+    #[test]
+    fn test_sum_rejects_mixed_assets_overflow_and_empty() {
+        let mpt = Amount::MPT {
+            num_units: 1,
+            is_positive: true,
+            mpt_id: MptId::new(1, AccountID::from([7u8; 20])),
+        };
+        let mixed = [Amount::XRP { num_drops: 1 }, mpt];
+        assert_eq!(Amount::sum(&mixed).err().unwrap().code(), INVALID_PARAMS);
+
+        let overflow = [
+            Amount::XRP {
+                num_drops: i64::MAX,
+            },
+            Amount::XRP { num_drops: 1 },
+        ];
+        assert_eq!(Amount::sum(&overflow).err().unwrap().code(), INVALID_PARAMS);
+
+        assert_eq!(Amount::sum(&[]).err().unwrap().code(), INVALID_PARAMS);
+    }
 
-        // Test from_bytes -> to_bytes round trip
-        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
-        assert_eq!(parsed, original);
+    #[test]
+    fn test_signed_sub_xrp_goes_negative() {
+        let received = Amount::XRP { num_drops: 100 };
+        let sent = Amount::XRP { num_drops: 250 };
+
+        assert_eq!(
+            received.signed_sub(&sent).unwrap(),
+            Amount::XRP { num_drops: -150 }
+        );
+        assert_eq!(
+            sent.signed_sub(&received).unwrap(),
+            Amount::XRP { num_drops: 150 }
+        );
+
+        let min = Amount::XRP {
+            num_drops: i64::MIN,
+        };
+        let one = Amount::XRP { num_drops: 1 };
+        assert_eq!(min.signed_sub(&one).err().unwrap().code(), INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_signed_sub_mpt_sign_handling() {
+        let mpt_id = MptId::new(1, AccountID::from([7u8; 20]));
+        let mpt = |num_units: u64, is_positive: bool| Amount::MPT {
+            num_units,
+            is_positive,
+            mpt_id,
+        };
+
+        assert_eq!(
+            mpt(10, true).signed_sub(&mpt(25, true)).unwrap(),
+            mpt(15, false)
+        );
+        assert_eq!(
+            mpt(10, false).signed_sub(&mpt(5, false)).unwrap(),
+            mpt(5, false)
+        );
+        assert_eq!(
+            mpt(10, true).signed_sub(&mpt(10, true)).unwrap(),
+            mpt(0, true)
+        );
+        assert_eq!(
+            mpt(u64::MAX, true)
+                .signed_sub(&mpt(1, false))
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn test_signed_sub_iou_requires_same_issue() {
+        let iou = |issuer: u8| Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID::from([issuer; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+
+        assert!(iou(1).signed_sub(&iou(1)).is_ok());
+        assert_eq!(
+            iou(1).signed_sub(&iou(2)).err().unwrap().code(),
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn test_signed_sub_rejects_mixed_assets() {
+        let xrp = Amount::XRP { num_drops: 1 };
+        let mpt = Amount::MPT {
+            num_units: 1,
+            is_positive: true,
+            mpt_id: MptId::from([0u8; 24]),
+        };
+        assert_eq!(xrp.signed_sub(&mpt).err().unwrap().code(), INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_from_issued_validates_currency() {
+        let issuer = AccountID::from([9u8; 20]);
+        let amount = OpaqueFloat(FLOAT_ONE);
+
+        assert!(Amount::from_issued(amount, issuer, Currency::from(*b"USD")).is_ok());
+        assert_eq!(
+            Amount::from_issued(amount, issuer, Currency::from(*b"XRP"))
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+    }
 
-        // Test to_stamount_bytes format
-        let (stamount_bytes, len) = original.to_stamount_bytes();
-        assert_eq!(len, 48);
-        assert_eq!(stamount_bytes[0], 0x60); // Flag byte
-        assert_eq!(&stamount_bytes[1..9], &VALUE.to_be_bytes()); // Amount
-        assert_eq!(&stamount_bytes[9..33], mpt_id.as_bytes()); // MptId
-        // Remaining bytes should be zero padding
-        assert_eq!(&stamount_bytes[33..48], &[0u8; 15]);
+    /// Builds an issued-amount float from raw parts, without normalizing.
+    fn iou_float(is_positive: bool, exponent: i32, mantissa: u64) -> OpaqueFloat {
+        let mut bits = IOU_ZERO | ((exponent + IOU_EXPONENT_BIAS) as u64) << 54 | mantissa;
+        if is_positive {
+            bits |= 0x4000000000000000;
+        }
+        OpaqueFloat(bits.to_be_bytes())
     }
 
     #[test]
-    fn test_round_trip_mpt_negative() {
-        // Test negative MPT amount
-        const VALUE: u64 = 250_000;
-        const SEQUENCE_NUM: u32 = 98765;
-        const ISSUER_BYTES: [u8; 20] = [0xCD; 20];
+    fn test_canonicalize_iou_scales_mantissa_into_range() {
+        // 1 * 10^0 becomes 10^15 * 10^-15
+        let canonical = canonicalize_iou(&iou_float(true, 0, 1)).unwrap();
+        assert_eq!(canonical, iou_float(true, -15, IOU_MIN_MANTISSA));
+        assert_eq!(canonical.0, FLOAT_ONE);
+
+        // Excess digits are truncated, not rounded
+        let canonical = canonicalize_iou(&iou_float(false, 0, 12_345_678_901_234_567)).unwrap();
+        assert_eq!(canonical, iou_float(false, 1, 1_234_567_890_123_456));
+
+        // Already-canonical values are unchanged
+        let value = iou_float(true, 5, 5_000_000_000_000_000);
+        assert_eq!(canonicalize_iou(&value).unwrap(), value);
+    }
 
-        let issuer = AccountID::from(ISSUER_BYTES);
-        let mpt_id = MptId::new(SEQUENCE_NUM, issuer);
-        let original = Amount::MPT {
-            num_units: VALUE,
-            is_positive: false,
-            mpt_id,
-        };
+    #[test]
+    fn test_normalize_issued_vectors() {
+        const MIN: u64 = IOU_MIN_MANTISSA;
+        const MAX: u64 = IOU_MAX_MANTISSA;
+        type Parts = (u64, i32);
+        let vectors: [(Parts, Option<Parts>); 16] = [
+            // Zero, whatever the exponent.
+            ((0, 0), Some((0, 0))),
+            ((0, 500), Some((0, 0))),
+            // Already canonical, at both mantissa bounds.
+            ((MIN, -15), Some((MIN, -15))),
+            ((MAX, -15), Some((MAX, -15))),
+            // Small mantissas scale up.
+            ((1, 0), Some((MIN, -15))),
+            ((12_345, 2), Some((1_234_500_000_000_000, -9))),
+            // Large mantissas scale down, truncating.
+            ((MAX + 1, 0), Some((MIN, 1))),
+            ((u64::MAX, 0), Some((1_844_674_407_370_955, 4))),
+            // Exponent bounds.
+            ((MIN, IOU_MIN_EXPONENT), Some((MIN, IOU_MIN_EXPONENT))),
+            ((MAX, IOU_MAX_EXPONENT), Some((MAX, IOU_MAX_EXPONENT))),
+            ((1, IOU_MAX_EXPONENT + 15), Some((MIN, IOU_MAX_EXPONENT))),
+            (
+                (u64::MAX, IOU_MIN_EXPONENT - 4),
+                Some((1_844_674_407_370_955, IOU_MIN_EXPONENT)),
+            ),
+            // Underflow becomes zero.
+            ((MIN, IOU_MIN_EXPONENT - 1), Some((0, 0))),
+            ((1, IOU_MIN_EXPONENT), Some((0, 0))),
+            // Overflow is an error.
+            ((MIN, IOU_MAX_EXPONENT + 1), None),
+            ((MAX + 1, IOU_MAX_EXPONENT), None),
+        ];
+        for ((mantissa, exponent), expected) in vectors {
+            let result = Amount::normalize_issued(mantissa, exponent);
+            match expected {
+                Some(parts) => assert_eq!(result.unwrap(), parts, "{mantissa}e{exponent}"),
+                None => assert_eq!(result.err().unwrap().code(), INVALID_FLOAT_INPUT),
+            }
+        }
+    }
 
-        // Create the expected byte layout for negative MPT
-        // MPT format: [0/type][0/sign][1/is-mpt][5/reserved][64/value][32/sequence][160/issuer]
-        let mut expected_bytes = [0u8; 48];
-        expected_bytes[0] = 0x20; // Negative MPT flag (0b0010_0000)
-        expected_bytes[1..9].copy_from_slice(&VALUE.to_be_bytes());
-        expected_bytes[9..13].copy_from_slice(&SEQUENCE_NUM.to_be_bytes());
-        expected_bytes[13..33].copy_from_slice(&ISSUER_BYTES);
+    #[test]
+    fn test_canonicalize_iou_zero_and_bounds() {
+        let zero = OpaqueFloat(IOU_ZERO.to_be_bytes());
+        assert_eq!(canonicalize_iou(&iou_float(false, 10, 0)).unwrap(), zero);
 
-        // Test from_bytes -> to_bytes round trip
-        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
-        assert_eq!(parsed, original);
+        // Too small to represent becomes zero
+        assert_eq!(canonicalize_iou(&iou_float(true, -97, 1)).unwrap(), zero);
 
-        // Test to_stamount_bytes format
-        let (stamount_bytes, len) = original.to_stamount_bytes();
-        assert_eq!(len, 48);
-        assert_eq!(stamount_bytes[0], 0x20); // Flag byte (negative)
-        assert_eq!(&stamount_bytes[1..9], &VALUE.to_be_bytes()); // Amount
-        assert_eq!(&stamount_bytes[9..33], mpt_id.as_bytes()); // MptId
-        // Remaining bytes should be zero padding
-        assert_eq!(&stamount_bytes[33..48], &[0u8; 15]);
+        // Too large to represent is an error
+        let too_large = iou_float(true, IOU_MAX_EXPONENT, 10_000_000_000_000_000);
+        assert!(canonicalize_iou(&too_large).is_err());
     }
 
     #[test]
-    fn test_round_trip_iou_positive() {
-        // Test positive IOU amount
-        const EXPONENT: u8 = 7;
-        const MANTISSA: u64 = 98765;
-        const CURRENCY_BYTES: [u8; 20] = [0xEF; 20];
-        const ISSUER_BYTES: [u8; 20] = [0x12; 20];
+    fn test_to_serialized_bytes_round_trip() {
+        let amounts = [
+            Amount::XRP { num_drops: 25 },
+            Amount::XRP { num_drops: -25 },
+            Amount::MPT {
+                num_units: 42,
+                is_positive: true,
+                mpt_id: MptId::new(7, AccountID::from([5u8; 20])),
+            },
+            Amount::IOU {
+                amount: iou_float(true, -15, IOU_MIN_MANTISSA),
+                issuer: AccountID::from([6u8; 20]),
+                currency: Currency::from(*b"USD"),
+            },
+        ];
+        let expected_lens = [8, 8, 33, 48];
+
+        for (amount, expected_len) in amounts.iter().zip(expected_lens) {
+            let (bytes, len) = amount.to_serialized_bytes().unwrap();
+            assert_eq!(len, expected_len);
+
+            // `from_bytes` expects a zero-padded 48-byte buffer
+            let mut padded = [0u8; AMOUNT_SIZE];
+            padded[..len].copy_from_slice(&bytes[..len]);
+            assert_eq!(&Amount::from_bytes(&padded).unwrap(), amount);
+        }
+    }
 
-        // Create the OpaqueFloat bytes manually
-        // IOU format: [1/type][1/sign][8/exponent][54/mantissa]
-        let mut opaque_float_bytes = [0u8; 8];
+    #[test]
+    fn test_parse_human_xrp() {
+        let parse = |s| match Amount::parse_human(s).unwrap() {
+            Amount::XRP { num_drops } => num_drops,
+            _ => panic!("expected XRP"),
+        };
+        assert_eq!(parse("1.5"), 1_500_000);
+        assert_eq!(parse("100"), 100_000_000);
+        assert_eq!(parse("-0.000001"), -1);
+        assert_eq!(parse("0.0000010"), 1);
+        assert_eq!(parse("2e3"), 2_000_000_000);
+        assert_eq!(parse("-0"), 0);
+        assert_eq!(parse("100000000000"), MAX_DROPS as i64);
+    }
 
-        // First byte: IOU positive flag (0xC0) with exponent bits
-        opaque_float_bytes[0] = 0xC0 | ((EXPONENT >> 2) & 0x3F);
+    #[test]
+    fn test_parse_human_xrp_rejects_malformed_values() {
+        for input in [
+            "",
+            "-",
+            "1.",
+            ".5",
+            "1.2.3",
+            "abc",
+            "+1",
+            "1e",
+            "0.0000001",
+            "100000000000.000001",
+        ] {
+            assert_eq!(
+                Amount::parse_human(input).err().unwrap().code(),
+                INVALID_FLOAT_INPUT,
+                "{input}"
+            );
+        }
+    }
 
-        // Second byte: first 2 bits for exponent, rest will be part of mantissa
-        opaque_float_bytes[1] = (EXPONENT & 0x03) << 6;
+    #[test]
+    fn test_parse_human_issued() {
+        let issuer = AccountID(crate::r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
 
-        let mantissa_bytes = MANTISSA.to_be_bytes();
+        let amount = Amount::parse_human("100/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap();
+        assert_eq!(
+            amount,
+            Amount::IOU {
+                amount: iou_float(true, -13, IOU_MIN_MANTISSA),
+                issuer,
+                currency: Currency::from(*b"USD"),
+            }
+        );
 
-        // Copy the mantissa bytes, preserving the exponent bits in opaque_float_bytes[1]
-        opaque_float_bytes[1] |= mantissa_bytes[0] & 0x3F;
-        opaque_float_bytes[2] = mantissa_bytes[1];
-        opaque_float_bytes[3] = mantissa_bytes[2];
-        opaque_float_bytes[4] = mantissa_bytes[3];
-        opaque_float_bytes[5] = mantissa_bytes[4];
-        opaque_float_bytes[6] = mantissa_bytes[5];
-        opaque_float_bytes[7] = mantissa_bytes[6];
+        let amount = Amount::parse_human(
+            "-1.5e-3/0158415500000000C1F76FF6ECB0BAC600000000/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh",
+        )
+        .unwrap();
+        match amount {
+            Amount::IOU {
+                amount, currency, ..
+            } => {
+                assert_eq!(amount, iou_float(false, -18, 1_500_000_000_000_000));
+                assert_eq!(currency.0[0], 0x01);
+                assert_eq!(currency.0[19], 0x00);
+            }
+            _ => panic!("expected an issued amount"),
+        }
 
-        let original = Amount::IOU {
-            amount: OpaqueFloat(opaque_float_bytes),
-            issuer: AccountID::from(ISSUER_BYTES),
-            currency: Currency::from(CURRENCY_BYTES),
-        };
+        // Zero is always positive, and extra precision is truncated.
+        let zero = Amount::parse_human("-0/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap();
+        assert!(matches!(zero, Amount::IOU { amount, .. } if amount.0 == IOU_ZERO.to_be_bytes()));
+        let long =
+            Amount::parse_human("1.23456789012345678/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh");
+        assert!(
+            matches!(long.unwrap(), Amount::IOU { amount, .. } if amount == iou_float(true, -15, 1_234_567_890_123_456))
+        );
+    }
 
-        // Create the expected byte layout for IOU
-        // IOU format: [1/type][1/sign][8/exponent][54/mantissa][160/currency][160/issuer]
-        let mut expected_bytes = [0u8; 48];
-        expected_bytes[0..8].copy_from_slice(&opaque_float_bytes);
-        expected_bytes[8..28].copy_from_slice(&CURRENCY_BYTES);
-        expected_bytes[28..48].copy_from_slice(&ISSUER_BYTES);
+    #[test]
+    fn test_parse_human_issued_errors_are_distinct() {
+        let code = |s| Amount::parse_human(s).err().unwrap().code();
+        assert_eq!(code("1/USD"), INVALID_PARAMS);
+        assert_eq!(
+            code("1/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh/x"),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            code("x/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            INVALID_FLOAT_INPUT
+        );
+        assert_eq!(
+            code("1/XRP/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            INVALID_DECODING
+        );
+        assert_eq!(
+            code("1/USDT/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            INVALID_DECODING
+        );
+        assert_eq!(
+            code("1/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTi"),
+            INVALID_ACCOUNT
+        );
+        assert_eq!(
+            code("1e200/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"),
+            INVALID_FLOAT_INPUT
+        );
+    }
 
-        // Test from_bytes -> to_bytes round trip
-        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
-        assert_eq!(parsed, original);
+    #[test]
+    fn test_sortable_amount_sorts_iou_by_value() {
+        let issuer = AccountID::from([4u8; 20]);
+        let usd = |amount| {
+            SortableAmount(Amount::IOU {
+                amount,
+                issuer,
+                currency: Currency::from(*b"USD"),
+            })
+        };
+        // 5, -2, 0.5, 100, -30 and 1 (non-canonical encoding)
+        let mut amounts = [
+            usd(iou_float(true, -15, 5 * IOU_MIN_MANTISSA)),
+            usd(iou_float(false, -15, 2 * IOU_MIN_MANTISSA)),
+            usd(iou_float(true, -16, 5 * IOU_MIN_MANTISSA)),
+            usd(iou_float(true, -13, IOU_MIN_MANTISSA)),
+            usd(iou_float(false, -14, 3 * IOU_MIN_MANTISSA)),
+            usd(iou_float(true, 0, 1)),
+        ];
+        amounts.sort_unstable();
+
+        let expected = [
+            usd(iou_float(false, -14, 3 * IOU_MIN_MANTISSA)),
+            usd(iou_float(false, -15, 2 * IOU_MIN_MANTISSA)),
+            usd(iou_float(true, -16, 5 * IOU_MIN_MANTISSA)),
+            usd(iou_float(true, -15, IOU_MIN_MANTISSA)),
+            usd(iou_float(true, -15, 5 * IOU_MIN_MANTISSA)),
+            usd(iou_float(true, -13, IOU_MIN_MANTISSA)),
+        ];
+        assert_eq!(amounts, expected);
+        assert_eq!(
+            usd(OpaqueFloat(IOU_ZERO.to_be_bytes())),
+            usd(iou_float(false, 0, 0))
+        );
+    }
 
-        // Test to_stamount_bytes format
-        let (stamount_bytes, len) = original.to_stamount_bytes();
-        assert_eq!(len, 48);
-        assert_eq!(&stamount_bytes[0..8], &opaque_float_bytes); // OpaqueFloat
-        assert_eq!(&stamount_bytes[8..28], &CURRENCY_BYTES); // Currency
-        assert_eq!(&stamount_bytes[28..48], &ISSUER_BYTES); // Issuer
-        // No padding for IOU - uses all 48 bytes
+    #[test]
+    fn test_sortable_amount_sorts_mpt_by_signed_value() {
+        let mpt_id = MptId::new(1, AccountID::from([5u8; 20]));
+        let mpt = |num_units, is_positive| {
+            SortableAmount(Amount::MPT {
+                num_units,
+                is_positive,
+                mpt_id,
+            })
+        };
+        let mut amounts = [mpt(10, true), mpt(7, false), mpt(0, true), mpt(3, true)];
+        amounts.sort_unstable();
+        assert_eq!(
+            amounts,
+            [mpt(7, false), mpt(0, true), mpt(3, true), mpt(10, true)]
+        );
     }
 
     #[test]
-    fn test_round_trip_iou_negative() {
-        // Test negative IOU amount
-        const EXPONENT: u8 = 3;
-        const MANTISSA: u64 = 12345;
-        const CURRENCY_BYTES: [u8; 20] = [0x34; 20];
-        const ISSUER_BYTES: [u8; 20] = [0x56; 20];
+    #[should_panic(expected = "different assets")]
+    fn test_sortable_amount_panics_on_mixed_assets() {
+        let xrp = SortableAmount(Amount::XRP { num_drops: 1 });
+        let usd = SortableAmount(Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID::from([4u8; 20]),
+            currency: Currency::from(*b"USD"),
+        });
+        let _ = xrp < usd;
+    }
 
-        // Create the OpaqueFloat bytes manually for negative amount
-        // IOU format: [1/type][0/sign][8/exponent][54/mantissa]
-        let mut opaque_float_bytes = [0u8; 8];
+    /// Renders an issued-amount float with [`write_iou_decimal`] into `out`.
+    fn iou_decimal<'a>(float: &OpaqueFloat, out: &'a mut [u8; 64]) -> &'a str {
+        let mut writer = SliceWriter::new(out);
+        write_iou_decimal(float, &mut writer).unwrap();
+        writer.into_str()
+    }
 
-        // First byte: IOU negative flag (0x80) with exponent bits
-        opaque_float_bytes[0] = 0x80 | ((EXPONENT >> 2) & 0x3F);
+    /// Renders `amount` with its `Display` impl into `out`.
+    fn display<'a>(amount: &Amount, out: &'a mut [u8; 128]) -> &'a str {
+        test_fmt::format(out, format_args!("{amount}"))
+    }
 
-        // Second byte: first 2 bits for exponent, rest will be part of mantissa
-        opaque_float_bytes[1] = (EXPONENT & 0x03) << 6;
+    #[test]
+    fn test_display_forms() {
+        let mut buf = [0u8; 128];
+        assert_eq!(
+            display(&Amount::XRP { num_drops: -12 }, &mut buf),
+            "-0.000012"
+        );
+        assert_eq!(
+            display(
+                &Amount::XRP {
+                    num_drops: 1_500_000
+                },
+                &mut buf
+            ),
+            "1.5"
+        );
+        assert_eq!(
+            display(
+                &Amount::XRP {
+                    num_drops: 2_000_000
+                },
+                &mut buf
+            ),
+            "2"
+        );
+        assert_eq!(display(&Amount::XRP { num_drops: 0 }, &mut buf), "0");
+        let usd = Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID::from([3u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert_eq!(
+            display(&usd, &mut buf),
+            "1/USD/0303030303030303030303030303030303030303"
+        );
+        let mpt = Amount::MPT {
+            num_units: 7,
+            is_positive: false,
+            mpt_id: MptId::new(1, AccountID::from([7u8; 20])),
+        };
+        assert_eq!(display(&mpt, &mut buf), "-7 MPT");
+    }
 
-        let mantissa_bytes = MANTISSA.to_be_bytes();
+    #[test]
+    fn test_issued_non_canonical_zero_displays_and_compares_as_zero() {
+        let usd = |bits: u64| Amount::IOU {
+            amount: OpaqueFloat(bits.to_be_bytes()),
+            issuer: AccountID::from([3u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        // The canonical zero has the positive bit (bit 62) clear.
+        let zero = usd(IOU_ZERO);
+        // Zero mantissa with the positive bit set: a non-canonical positive zero.
+        let positive_zero = usd(0xC000000000000000);
+        // Zero mantissa with the positive bit clear, like the canonical zero, but stray exponent
+        // bits; and the same with the positive bit set.
+        let scaled_zero = usd(IOU_ZERO | (10u64 << 54));
+        let positive_scaled_zero = usd(0xC000000000000000 | (10u64 << 54));
+
+        let mut buf = [0u8; 128];
+        for non_canonical in [&positive_zero, &scaled_zero, &positive_scaled_zero] {
+            assert_eq!(
+                display(non_canonical, &mut buf),
+                "0/USD/0303030303030303030303030303030303030303"
+            );
+            assert_eq!(*non_canonical, zero);
+        }
+        assert_eq!(scaled_zero, positive_zero);
 
-        // Copy the mantissa bytes, preserving the exponent bits in opaque_float_bytes[1]
-        opaque_float_bytes[1] |= mantissa_bytes[0] & 0x3F;
-        opaque_float_bytes[2] = mantissa_bytes[1];
-        opaque_float_bytes[3] = mantissa_bytes[2];
-        opaque_float_bytes[4] = mantissa_bytes[3];
-        opaque_float_bytes[5] = mantissa_bytes[4];
-        opaque_float_bytes[6] = mantissa_bytes[5];
-        opaque_float_bytes[7] = mantissa_bytes[6];
+        // Zero is still tied to its currency and issuer, and non-zero values to their bits.
+        let eur_zero = Amount::IOU {
+            amount: OpaqueFloat(0xC000000000000000u64.to_be_bytes()),
+            issuer: AccountID::from([3u8; 20]),
+            currency: Currency::from(*b"EUR"),
+        };
+        assert_ne!(eur_zero, zero);
+        assert_ne!(usd(0xD4838D7EA4C68000), usd(0x94838D7EA4C68000));
+    }
 
-        let original = Amount::IOU {
-            amount: OpaqueFloat(opaque_float_bytes),
-            issuer: AccountID::from(ISSUER_BYTES),
-            currency: Currency::from(CURRENCY_BYTES),
+    #[test]
+    fn test_display_round_trips_through_parse_human() {
+        let round_trip = |amount: Amount| {
+            let mut buf = [0u8; 128];
+            let text = display(&amount, &mut buf);
+            assert_eq!(Amount::parse_human(text).unwrap(), amount, "{text}");
         };
 
-        // Create the expected byte layout for negative IOU
-        // IOU format: [1/type][0/sign][8/exponent][54/mantissa][160/currency][160/issuer]
-        let mut expected_bytes = [0u8; 48];
-        expected_bytes[0..8].copy_from_slice(&opaque_float_bytes);
-        expected_bytes[8..28].copy_from_slice(&CURRENCY_BYTES);
-        expected_bytes[28..48].copy_from_slice(&ISSUER_BYTES);
+        for num_drops in [
+            0,
+            1,
+            -12,
+            1_500_000,
+            -2_000_000,
+            123_456_789,
+            MAX_DROPS as i64,
+        ] {
+            round_trip(Amount::XRP { num_drops });
+        }
 
-        // Test from_bytes -> to_bytes round trip
-        let parsed = Amount::from_bytes(&expected_bytes).unwrap();
-        assert_eq!(parsed, original);
+        let issuer = AccountID(crate::r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
+        let non_standard = Currency::from(
+            crate::decode_hex_20(b"039C99CD9AB0B70B32ECDA51EAAE471625608EA2").unwrap(),
+        );
+        for (bits, currency) in [
+            (FLOAT_ONE, Currency::from(*b"USD")),
+            (
+                iou_float(false, -3, 1_250_000_000_000_000).0,
+                Currency::from(*b"EUR"),
+            ),
+            (iou_float(true, 20, 9_999_999_999_999_999).0, non_standard),
+            (IOU_ZERO.to_be_bytes(), Currency::from(*b"USD")),
+        ] {
+            round_trip(Amount::IOU {
+                amount: OpaqueFloat(bits),
+                issuer,
+                currency,
+            });
+        }
 
-        // Test to_stamount_bytes format
-        let (stamount_bytes, len) = original.to_stamount_bytes();
-        assert_eq!(len, 48);
-        assert_eq!(&stamount_bytes[0..8], &opaque_float_bytes); // OpaqueFloat
-        assert_eq!(&stamount_bytes[8..28], &CURRENCY_BYTES); // Currency
-        assert_eq!(&stamount_bytes[28..48], &ISSUER_BYTES); // Issuer
-        // No padding for IOU - uses all 48 bytes
+        // The issuer is also accepted as a classic address, as people usually write it.
+        assert_eq!(
+            Amount::parse_human("1/USD/rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap(),
+            Amount::parse_human("1/USD/B5F762798A53D543A014CAF8B297CFF8F2F937E8").unwrap()
+        );
     }
 
     #[test]
-    fn test_round_trip_edge_cases() {
-        // Test XRP with maximum value that fits in 57 bits
-        let max_57_bit_value = MASK_57_BIT as i64;
-        let max_xrp = Amount::XRP {
-            num_drops: max_57_bit_value,
-        };
-        let mut max_xrp_bytes = [0u8; 48];
+    fn test_write_iou_decimal_is_exact() {
+        let mut buf = [0u8; 64];
+        assert_eq!(iou_decimal(&OpaqueFloat(FLOAT_ONE), &mut buf), "1");
+        assert_eq!(iou_decimal(&iou_float(true, 0, 0), &mut buf), "0");
+        assert_eq!(iou_decimal(&iou_float(false, 0, 0), &mut buf), "0");
+        // 1234.5
+        assert_eq!(
+            iou_decimal(&iou_float(true, -12, 1_234_500_000_000_000), &mut buf),
+            "1234.5"
+        );
+        // All 16 significant digits survive, which an f64 cannot guarantee.
+        assert_eq!(
+            iou_decimal(&iou_float(true, -15, 9_007_199_254_740_993), &mut buf),
+            "9.007199254740993"
+        );
+        assert_eq!(
+            iou_decimal(&iou_float(false, -18, IOU_MIN_MANTISSA), &mut buf),
+            "-0.001"
+        );
+        assert_eq!(
+            iou_decimal(&iou_float(true, -25, 1_500_000_000_000_000), &mut buf),
+            "0.00000000015"
+        );
+    }
 
-        // Create the full 64-bit value with flag bits
-        let full_value = (max_57_bit_value as u64) | 0x4000000000000000u64; // Add positive flag
-        max_xrp_bytes[0..8].copy_from_slice(&full_value.to_be_bytes());
+    #[test]
+    fn test_write_iou_decimal_uses_scientific_outside_range() {
+        let mut buf = [0u8; 64];
+        assert_eq!(
+            iou_decimal(&iou_float(true, 5, IOU_MIN_MANTISSA), &mut buf),
+            "1e20"
+        );
+        assert_eq!(
+            iou_decimal(&iou_float(false, -30, 1_500_000_000_000_000), &mut buf),
+            "-15e-16"
+        );
+        assert_eq!(
+            iou_decimal(&iou_float(true, -4, 1_234_567_890_123_456), &mut buf),
+            "1234567890123456e-4"
+        );
+    }
 
-        let parsed_max_xrp = Amount::from_bytes(&max_xrp_bytes).unwrap();
-        assert_eq!(parsed_max_xrp, max_xrp);
+    #[test]
+    fn test_from_field_bytes_xrp() {
+        let mut bytes = 1_000_000u64.to_be_bytes();
+        bytes[0] |= 0x40;
+        assert_eq!(
+            Amount::from_field_bytes(&bytes).unwrap(),
+            Amount::XRP {
+                num_drops: 1_000_000
+            }
+        );
+
+        // An XRP header padded to the full amount size is the wrong length.
+        let mut padded = [0u8; AMOUNT_SIZE];
+        padded[..8].copy_from_slice(&bytes);
+        assert_eq!(
+            Amount::from_field_bytes(&padded).err().unwrap().code(),
+            INVALID_FIELD
+        );
+
+        // Reserved bits set.
+        bytes[0] |= 0x02;
+        assert_eq!(
+            Amount::from_field_bytes(&bytes).err().unwrap().code(),
+            INVALID_DECODING
+        );
+    }
 
-        // Test XRP with maximum negative value that fits in 57 bits
-        let min_xrp = Amount::XRP {
-            num_drops: -max_57_bit_value,
+    #[test]
+    fn test_from_field_bytes_issued() {
+        let amount = Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID::from([3u8; 20]),
+            currency: Currency::from(*b"USD"),
         };
-        let mut min_xrp_bytes = [0u8; 48];
+        let (bytes, len) = amount.to_serialized_bytes().unwrap();
+        assert_eq!(Amount::from_field_bytes(&bytes[..len]).unwrap(), amount);
+        assert_eq!(
+            Amount::from_field_bytes(&bytes[..8]).err().unwrap().code(),
+            INVALID_FIELD
+        );
+
+        // "XRP" cannot be issued.
+        let mut bad_currency = bytes;
+        bad_currency[8..28].copy_from_slice(Currency::from(*b"XRP").as_bytes());
+        assert_eq!(
+            Amount::from_field_bytes(&bad_currency)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_DECODING
+        );
+
+        // A non-canonical mantissa of 1.
+        let mut non_canonical = bytes;
+        non_canonical[..8].copy_from_slice(&iou_float(true, 0, 1).0);
+        assert_eq!(
+            Amount::from_field_bytes(&non_canonical)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_DECODING
+        );
+    }
 
-        // Create the full 64-bit value without positive flag (negative)
-        let full_value = max_57_bit_value as u64; // No positive flag = negative
-        min_xrp_bytes[0..8].copy_from_slice(&full_value.to_be_bytes());
+    #[test]
+    fn test_from_tx_field_with_test_host() {
+        // The test host reports a full 48-byte field of zeros, which is an XRP header with an
+        // issued amount's length.
+        assert_eq!(
+            Amount::from_tx_field(crate::sfield::Amount)
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_FIELD
+        );
+    }
 
-        let parsed_min_xrp = Amount::from_bytes(&min_xrp_bytes).unwrap();
-        assert_eq!(parsed_min_xrp, min_xrp);
+    #[test]
+    fn test_from_tx_field_with_mock_host() {
+        use crate::host::error_codes::FIELD_NOT_FOUND;
+        use crate::host::mock::MockHost;
+
+        let host = MockHost::new();
+
+        // 1 XRP, as the 8-byte serialized field.
+        host.set_tx_field(
+            crate::sfield::Amount,
+            &[0x40, 0x00, 0x00, 0x00, 0x00, 0x0F, 0x42, 0x40],
+        );
+        assert_eq!(
+            Amount::from_tx_field(crate::sfield::Amount).unwrap(),
+            Amount::XRP {
+                num_drops: 1_000_000
+            }
+        );
+
+        // 1 USD, as the 48-byte serialized field: value, currency, issuer.
+        let currency = Currency::from(*b"USD");
+        let issuer = AccountID::from([7u8; 20]);
+        let mut iou = [0u8; 48];
+        iou[..8].copy_from_slice(&FLOAT_ONE);
+        iou[8..28].copy_from_slice(currency.as_bytes());
+        iou[28..].copy_from_slice(&issuer.0);
+        host.set_tx_field(crate::sfield::SendMax, &iou);
+        assert_eq!(
+            Amount::from_tx_field(crate::sfield::SendMax).unwrap(),
+            Amount::IOU {
+                amount: OpaqueFloat(FLOAT_ONE),
+                issuer,
+                currency,
+            }
+        );
+
+        // An absent field is reported as such, not as a malformed amount.
+        host.remove_tx_field(crate::sfield::DeliverMin);
+        assert_eq!(
+            Amount::from_tx_field(crate::sfield::DeliverMin)
+                .err()
+                .unwrap()
+                .code(),
+            FIELD_NOT_FOUND
+        );
+    }
 
-        // Test XRP with zero value
-        let zero_xrp = Amount::XRP { num_drops: 0 };
-        let mut zero_xrp_bytes = [0u8; 48];
-        zero_xrp_bytes[0] = 0x40; // Positive flag (zero is considered positive)
+    #[test]
+    fn test_percentage_of_rounds_down() {
+        let xrp = |num_drops| Amount::XRP { num_drops };
+        assert_eq!(xrp(1).percentage_of(&xrp(3)).unwrap(), 3_333);
+        assert_eq!(xrp(2).percentage_of(&xrp(3)).unwrap(), 6_666);
+        assert_eq!(xrp(5).percentage_of(&xrp(5)).unwrap(), 10_000);
+        assert_eq!(xrp(3).percentage_of(&xrp(2)).unwrap(), 15_000);
+        assert_eq!(xrp(0).percentage_of(&xrp(2)).unwrap(), 0);
+        // Less than one basis point.
+        assert_eq!(xrp(9_999).percentage_of(&xrp(100_000_000)).unwrap(), 0);
+        assert_eq!(xrp(i64::MAX).percentage_of(&xrp(1)).unwrap(), u32::MAX);
+    }
 
-        let parsed_zero_xrp = Amount::from_bytes(&zero_xrp_bytes).unwrap();
-        assert_eq!(parsed_zero_xrp, zero_xrp);
+    #[test]
+    fn test_percentage_of_issued() {
+        let issuer = AccountID::from([4u8; 20]);
+        let currency = Currency::from(*b"USD");
+        // mantissa * 10^exponent, built in non-canonical form.
+        let iou = |mantissa: u64, exponent: i32| Amount::IOU {
+            amount: iou_float(true, exponent, mantissa),
+            issuer,
+            currency,
+        };
+        assert_eq!(iou(5, -1).percentage_of(&iou(1, 0)).unwrap(), 5_000);
+        assert_eq!(iou(1, 0).percentage_of(&iou(3, 0)).unwrap(), 3_333);
+        assert_eq!(iou(1, 3).percentage_of(&iou(3, 4)).unwrap(), 333);
+        assert_eq!(iou(2, 1).percentage_of(&iou(1, 0)).unwrap(), 200_000);
+        assert_eq!(iou(1, -30).percentage_of(&iou(1, 0)).unwrap(), 0);
+        assert_eq!(iou(1, 30).percentage_of(&iou(1, 0)).unwrap(), u32::MAX);
+    }
 
-        // Test that values larger than 57 bits get properly masked during parsing
-        let large_value = i64::MAX;
-        let expected_masked_value = (large_value as u64 & MASK_57_BIT) as i64;
-        let large_xrp = Amount::XRP {
-            num_drops: expected_masked_value,
+    #[test]
+    fn test_percentage_of_rejects_invalid_inputs() {
+        let xrp = |num_drops| Amount::XRP { num_drops };
+        let code = |result: host::Result<u32>| result.err().unwrap().code();
+        assert_eq!(code(xrp(1).percentage_of(&xrp(0))), INVALID_PARAMS);
+        assert_eq!(code(xrp(-1).percentage_of(&xrp(2))), INVALID_PARAMS);
+
+        let mpt = Amount::MPT {
+            num_units: 1,
+            is_positive: true,
+            mpt_id: MptId::from([0u8; 24]),
         };
+        assert_eq!(code(xrp(1).percentage_of(&mpt)), INVALID_PARAMS);
+        assert_eq!(mpt.percentage_of(&mpt).unwrap(), 10_000);
+    }
 
-        let mut large_xrp_bytes = [0u8; 48];
-        // Create the full 64-bit value with XRP positive flag and the large value
-        let masked_value = (large_value as u64) & MASK_57_BIT;
-        let full_value = masked_value | 0x4000000000000000u64; // Add positive flag (bit 62)
-        large_xrp_bytes[0..8].copy_from_slice(&full_value.to_be_bytes());
+    #[test]
+    fn test_is_canonical_mantissa_bounds() {
+        let iou = |mantissa: u64, exponent: i32| Amount::IOU {
+            amount: iou_float(true, exponent, mantissa),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert!(iou(IOU_MIN_MANTISSA, -15).is_canonical());
+        assert!(iou(IOU_MAX_MANTISSA, -15).is_canonical());
+        assert!(!iou(IOU_MIN_MANTISSA - 1, -15).is_canonical());
+        assert!(!iou(IOU_MAX_MANTISSA + 1, -15).is_canonical());
+        assert!(iou(IOU_MIN_MANTISSA, IOU_MIN_EXPONENT).is_canonical());
+        assert!(iou(IOU_MIN_MANTISSA, IOU_MAX_EXPONENT).is_canonical());
+        assert!(!iou(IOU_MIN_MANTISSA, IOU_MIN_EXPONENT - 1).is_canonical());
+        assert!(!iou(IOU_MIN_MANTISSA, IOU_MAX_EXPONENT + 1).is_canonical());
+        // Zero must be exactly the canonical encoding, without a sign or exponent.
+        assert!(!iou(0, -97).is_canonical());
+        let zero = Amount::IOU {
+            amount: OpaqueFloat(IOU_ZERO.to_be_bytes()),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert!(zero.is_canonical());
+        assert!(Amount::XRP { num_drops: -5 }.is_canonical());
+    }
 
-        let parsed_large_xrp = Amount::from_bytes(&large_xrp_bytes).unwrap();
-        assert_eq!(parsed_large_xrp, large_xrp);
+    #[test]
+    fn test_canonicalize() {
+        let one = Amount::IOU {
+            amount: iou_float(true, 0, 1),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert!(!one.is_canonical());
+        let canonical = one.canonicalize().unwrap();
+        assert!(canonical.is_canonical());
+        match canonical {
+            Amount::IOU { amount, .. } => assert_eq!(amount.0, FLOAT_ONE),
+            _ => panic!("expected an issued amount"),
+        }
+        // Too large to represent.
+        let huge = Amount::IOU {
+            amount: iou_float(true, 80, IOU_MANTISSA_MASK),
+            issuer: AccountID::from([1u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert_eq!(
+            huge.canonicalize().err().unwrap().code(),
+            INVALID_FLOAT_INPUT
+        );
+    }
+
+    #[test]
+    fn test_is_same_asset() {
+        let usd = |issuer: u8| Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID::from([issuer; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        let xrp = Amount::XRP { num_drops: 1 };
+        assert!(xrp.is_same_asset(&Amount::XRP { num_drops: -7 }));
+        assert!(usd(1).is_same_asset(&usd(1)));
+        assert!(!usd(1).is_same_asset(&usd(2)));
+        assert!(!usd(1).is_same_asset(&xrp));
     }
 }
@@ -1,4 +1,5 @@
 use crate::core::types::nft::NFT_URI_MAX_SIZE;
+use crate::host::{Error, Result};
 
 /// Default blob size for general use (memos, etc.)
 pub const DEFAULT_BLOB_SIZE: usize = 1024;
@@ -28,7 +29,18 @@ pub const MEMO_BLOB_SIZE: usize = DEFAULT_BLOB_SIZE;
 /// // Create a smaller 256-byte blob for URIs
 /// let uri_blob: Blob<NFT_URI_MAX_SIZE> = Blob::new();
 /// ```
-#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+///
+/// ## Derived Traits
+///
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+///
+/// Note: `Copy` is intentionally not derived because `N` is usually large (1024 bytes for a
+/// [`StandardBlob`]), and an implicit copy of that size is expensive in WASM. Pass blobs as
+/// `&Blob<N>` (or borrow the bytes with [`AsRef::as_ref`]) and call `.clone()` where an owned
+/// copy is really needed. Code written against earlier versions that moved a blob and then kept
+/// using the original must now either borrow or clone explicitly.
+#[derive(Debug, Clone, Eq, PartialEq)]
 #[repr(C)]
 pub struct Blob<const N: usize> {
     pub data: [u8; N],
@@ -56,6 +68,26 @@ impl<const N: usize> Blob<N> {
         Self { data, len }
     }
 
+    /// Creates a blob from a backing array and the number of valid bytes in it, the inverse of
+    /// [`Blob::into_inner`].
+    ///
+    /// Returns `Error::BufferTooSmall` if `len` exceeds the capacity `N`.
+    #[inline]
+    pub fn from_parts(data: [u8; N], len: usize) -> Result<Self> {
+        if len > N {
+            return Result::Err(Error::BufferTooSmall);
+        }
+        Result::Ok(Self { data, len })
+    }
+
+    /// Consumes the blob, returning its backing array and the number of valid bytes in it.
+    ///
+    /// Bytes of the array past the length are whatever the buffer last held.
+    #[inline]
+    pub fn into_inner(self) -> ([u8; N], usize) {
+        (self.data, self.len)
+    }
+
     /// Returns the actual length of the data in the blob.
     #[inline]
     pub const fn len(&self) -> usize {
@@ -79,8 +111,113 @@ impl<const N: usize> Blob<N> {
     pub fn as_slice(&self) -> &[u8] {
         &self.data[..self.len]
     }
+
+    /// Copies the valid bytes to the start of `dst`, returning how many were copied.
+    ///
+    /// Bytes of `dst` past the copied length are left untouched. Returns
+    /// `Error::BufferTooSmall` without writing anything if `dst` is shorter than [`Blob::len`].
+    #[inline]
+    pub fn copy_to_slice(&self, dst: &mut [u8]) -> Result<usize> {
+        match dst.get_mut(..self.len) {
+            Some(prefix) => {
+                prefix.copy_from_slice(self.as_slice());
+                Result::Ok(self.len)
+            }
+            None => Result::Err(Error::BufferTooSmall),
+        }
+    }
+
+    /// Reverses the order of the valid bytes in place, e.g. to flip the endianness of an
+    /// integer field. Unused capacity is not touched.
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.data[..self.len].reverse();
+    }
+
+    /// Writes `src` into the blob starting at `offset`, extending `len` if the write ends past
+    /// the current length.
+    ///
+    /// Bytes between the old length and `offset` are left as they are in the underlying buffer
+    /// (zero for a freshly created blob). Returns `Error::BufferTooSmall` without modifying the
+    /// blob if the write would not fit within the capacity `N`.
+    pub fn write_at(&mut self, offset: usize, src: &[u8]) -> Result<()> {
+        let end = match offset.checked_add(src.len()) {
+            Some(end) if end <= N => end,
+            _ => return Result::Err(Error::BufferTooSmall),
+        };
+        self.data[offset..end].copy_from_slice(src);
+        if end > self.len {
+            self.len = end;
+        }
+        Result::Ok(())
+    }
+
+    /// Appends `src` after the valid bytes.
+    ///
+    /// Returns `Error::BufferTooSmall` without modifying the blob if the result would exceed the
+    /// capacity `N`.
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, src: &[u8]) -> Result<()> {
+        self.write_at(self.len, src)
+    }
+
+    /// Appends the valid bytes of `other`, which may have a different capacity, such as when
+    /// concatenating the parts of a preimage.
+    ///
+    /// Returns `Error::BufferTooSmall` without modifying the blob if the result would exceed the
+    /// capacity `N`.
+    #[inline]
+    pub fn try_extend_from_blob<const M: usize>(&mut self, other: &Blob<M>) -> Result<()> {
+        self.try_extend_from_slice(other.as_slice())
+    }
+
+    /// Returns the offset of the first occurrence of `needle` within the valid bytes, if any.
+    ///
+    /// An empty `needle` matches at offset 0. The search is a simple linear scan, which is
+    /// adequate for memo-sized data.
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        self.as_slice()
+            .windows(needle.len())
+            .position(|window| window == needle)
+    }
+
+    /// Borrows the first `M` valid bytes as a fixed-size array, or returns `None` if the blob
+    /// holds fewer than `M` bytes.
+    ///
+    /// This reads a fixed-size prefix (such as an NFToken ID at the start of a memo) without
+    /// copying.
+    #[inline]
+    pub fn as_array_ref<const M: usize>(&self) -> Option<&[u8; M]> {
+        self.as_slice().first_chunk::<M>()
+    }
+
+    /// Borrows the last `M` valid bytes as a fixed-size array, or returns `None` if the blob
+    /// holds fewer than `M` bytes.
+    ///
+    /// This is the suffix counterpart of [`Blob::as_array_ref`], for layouts that end with a
+    /// fixed-size field (such as a 4-byte sequence at the end of a memo).
+    #[inline]
+    pub fn last_chunk<const M: usize>(&self) -> Option<&[u8; M]> {
+        self.as_slice().last_chunk::<M>()
+    }
+
+    /// Returns an iterator over the valid bytes in `chunk_size`-byte slices, for decoding
+    /// fixed-size records packed into a blob (such as 32-byte hashes in a memo).
+    ///
+    /// If the length is not a multiple of `chunk_size`, the last chunk is shorter. Like
+    /// [`slice::chunks`], this panics if `chunk_size` is 0.
+    #[inline]
+    pub fn chunks(&self, chunk_size: usize) -> ChunksIter<'_> {
+        self.as_slice().chunks(chunk_size)
+    }
 }
 
+/// Iterator returned by [`Blob::chunks`].
+pub type ChunksIter<'a> = core::slice::Chunks<'a, u8>;
+
 impl<const N: usize> From<[u8; N]> for Blob<N> {
     fn from(bytes: [u8; N]) -> Self {
         Self {
@@ -96,6 +233,14 @@ impl<const N: usize> Default for Blob<N> {
     }
 }
 
+impl<const N: usize> AsRef<[u8]> for Blob<N> {
+    /// Borrows the valid bytes of the blob, equivalent to [`Blob::as_slice`].
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
 /// Type alias for the standard 1024-byte blob (for memos and general use)
 pub type StandardBlob = Blob<DEFAULT_BLOB_SIZE>;
 
@@ -113,6 +258,7 @@ pub const EMPTY_BLOB: EmptyBlob = Blob {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::host::error_codes::BUFFER_TOO_SMALL;
 
     #[test]
     fn test_new_creates_empty_blob() {
@@ -210,16 +356,23 @@ mod tests {
     #[test]
     fn test_clone_creates_independent_copy() {
         let blob1: Blob<5> = Blob::from_slice(&[1, 2, 3]);
-        let mut blob2 = blob1;
+        let mut blob2 = blob1.clone();
 
         // Modify blob2
         blob2.data[0] = 99;
 
-        // blob1 should be unchanged (Copy trait means independent copy)
+        // blob1 should be unchanged
         assert_eq!(blob1.data[0], 1);
         assert_eq!(blob2.data[0], 99);
     }
 
+    #[test]
+    fn test_as_ref_borrows_valid_bytes() {
+        let blob: Blob<8> = Blob::from_slice(&[1, 2, 3]);
+        let bytes: &[u8] = blob.as_ref();
+        assert_eq!(bytes, &[1, 2, 3]);
+    }
+
     #[test]
     fn test_standard_blob_type_alias() {
         let blob: StandardBlob = Blob::new();
@@ -287,6 +440,158 @@ mod tests {
         assert_eq!(blob.as_slice(), &[0xFF, 0x00, 0xAB, 0xCD, 0xEF]);
     }
 
+    #[test]
+    fn test_write_at_extends_len() {
+        let mut blob: Blob<10> = Blob::new();
+        assert!(blob.write_at(4, &[7, 8]).is_ok());
+        assert_eq!(blob.len(), 6);
+        assert_eq!(blob.as_slice(), &[0, 0, 0, 0, 7, 8]);
+
+        // Writing inside the current length does not shrink it
+        assert!(blob.write_at(0, &[1, 2]).is_ok());
+        assert_eq!(blob.len(), 6);
+        assert_eq!(blob.as_slice(), &[1, 2, 0, 0, 7, 8]);
+    }
+
+    #[test]
+    fn test_write_at_fills_to_exact_capacity() {
+        let mut blob: Blob<4> = Blob::new();
+        assert!(blob.write_at(2, &[1, 2]).is_ok());
+        assert_eq!(blob.len(), 4);
+    }
+
+    #[test]
+    fn test_write_at_overflow_errors_without_modifying() {
+        let mut blob: Blob<4> = Blob::from_slice(&[1, 2]);
+        assert_eq!(
+            blob.write_at(3, &[9, 9]).err().unwrap().code(),
+            BUFFER_TOO_SMALL
+        );
+        assert_eq!(
+            blob.write_at(5, &[]).err().unwrap().code(),
+            BUFFER_TOO_SMALL
+        );
+        assert_eq!(
+            blob.write_at(usize::MAX, &[1]).err().unwrap().code(),
+            BUFFER_TOO_SMALL
+        );
+        assert_eq!(blob.as_slice(), &[1, 2]);
+        assert_eq!(blob.data, [1, 2, 0, 0]);
+    }
+
+    #[test]
+    fn test_try_extend_from_blob_up_to_capacity() {
+        let mut blob: Blob<6> = Blob::from_slice(&[1, 2]);
+        let other: Blob<8> = Blob::from_slice(&[3, 4, 5, 6]);
+        assert!(blob.try_extend_from_blob(&other).is_ok());
+        assert_eq!(blob.as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+        // Appending nothing to a full blob is fine.
+        assert!(blob.try_extend_from_blob(&Blob::<4>::new()).is_ok());
+        assert_eq!(blob.len(), 6);
+    }
+
+    #[test]
+    fn test_try_extend_from_blob_overflow_errors_without_modifying() {
+        let mut blob: Blob<4> = Blob::from_slice(&[1, 2]);
+        let other: Blob<4> = Blob::from_slice(&[3, 4, 5]);
+        assert_eq!(
+            blob.try_extend_from_blob(&other).err().unwrap().code(),
+            BUFFER_TOO_SMALL
+        );
+        assert_eq!(blob.as_slice(), &[1, 2]);
+        assert_eq!(blob.data, [1, 2, 0, 0]);
+
+        assert!(blob.try_extend_from_slice(&[3, 4]).is_ok());
+        assert_eq!(blob.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_copy_to_slice() {
+        let blob: Blob<8> = Blob::from_slice(&[1, 2, 3]);
+        let mut dst = [9u8; 4];
+        assert_eq!(blob.copy_to_slice(&mut dst).unwrap(), 3);
+        assert_eq!(dst, [1, 2, 3, 9]);
+
+        let mut exact = [0u8; 3];
+        assert_eq!(blob.copy_to_slice(&mut exact).unwrap(), 3);
+        assert_eq!(Blob::<8>::new().copy_to_slice(&mut []).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_copy_to_slice_too_small_errors_without_writing() {
+        let blob: Blob<8> = Blob::from_slice(&[1, 2, 3]);
+        let mut dst = [9u8; 2];
+        assert_eq!(
+            blob.copy_to_slice(&mut dst).err().unwrap().code(),
+            BUFFER_TOO_SMALL
+        );
+        assert_eq!(dst, [9, 9]);
+    }
+
+    #[test]
+    fn test_reverse_even_and_odd_lengths() {
+        let mut even: Blob<8> = Blob::from_slice(&[1, 2, 3, 4]);
+        even.reverse();
+        assert_eq!(even.as_slice(), &[4, 3, 2, 1]);
+
+        let mut odd: Blob<8> = Blob::from_slice(&[1, 2, 3]);
+        odd.reverse();
+        assert_eq!(odd.as_slice(), &[3, 2, 1]);
+    }
+
+    #[test]
+    fn test_reverse_leaves_unused_capacity() {
+        let mut blob: Blob<4> = Blob::from_parts([1, 2, 3, 0xFF], 3).unwrap();
+        blob.reverse();
+        assert_eq!(blob.into_inner(), ([3, 2, 1, 0xFF], 3));
+
+        let mut empty: Blob<4> = Blob::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_into_inner_and_from_parts_round_trip() {
+        let blob: Blob<4> = Blob::from_slice(&[1, 2, 3]);
+        let (data, len) = blob.clone().into_inner();
+        assert_eq!(data, [1, 2, 3, 0]);
+        assert_eq!(len, 3);
+        assert_eq!(Blob::from_parts(data, len).unwrap(), blob);
+
+        assert_eq!(
+            Blob::from_parts([0u8; 4], 5).err().unwrap().code(),
+            BUFFER_TOO_SMALL
+        );
+    }
+
+    #[test]
+    fn test_find_returns_first_offset() {
+        let blob: Blob<16> = Blob::from_slice(b"key:value:more");
+        assert_eq!(blob.find(b":"), Some(3));
+        assert_eq!(blob.find(b"value"), Some(4));
+        assert_eq!(blob.find(b""), Some(0));
+    }
+
+    #[test]
+    fn test_find_not_found() {
+        let blob: Blob<16> = Blob::from_slice(b"abc");
+        assert_eq!(blob.find(b"abcd"), None);
+        assert_eq!(blob.find(b"x"), None);
+
+        // Bytes beyond `len` are not searched
+        let mut blob: Blob<8> = Blob::from_slice(b"ab");
+        blob.data[2] = b'c';
+        assert_eq!(blob.find(b"c"), None);
+    }
+
+    #[test]
+    fn test_find_overlapping_match() {
+        let blob: Blob<16> = Blob::from_slice(b"aaab");
+        assert_eq!(blob.find(b"aab"), Some(1));
+        assert_eq!(blob.find(b"aa"), Some(0));
+    }
+
     #[test]
     fn test_capacity_is_const() {
         let blob1: Blob<10> = Blob::new();
@@ -296,4 +601,37 @@ mod tests {
         assert_eq!(blob1.capacity(), 10);
         assert_eq!(blob2.capacity(), 10);
     }
+
+    #[test]
+    fn test_chunks_with_partial_last_chunk() {
+        let blob: Blob<16> = Blob::from_slice(&[1, 2, 3, 4, 5, 6, 7]);
+        let mut chunks = blob.chunks(3);
+        assert_eq!(chunks.next(), Some(&[1u8, 2, 3][..]));
+        assert_eq!(chunks.next(), Some(&[4u8, 5, 6][..]));
+        assert_eq!(chunks.next(), Some(&[7u8][..]));
+        assert_eq!(chunks.next(), None);
+
+        // Unused capacity is never visited.
+        assert_eq!(Blob::<16>::new().chunks(4).count(), 0);
+    }
+
+    #[test]
+    fn test_as_array_ref() {
+        let blob: Blob<8> = Blob::from_slice(&[1, 2, 3, 4]);
+        assert_eq!(blob.as_array_ref::<2>(), Some(&[1u8, 2]));
+        assert_eq!(blob.as_array_ref::<4>(), Some(&[1u8, 2, 3, 4]));
+        // Unused capacity does not count towards the length.
+        assert_eq!(blob.as_array_ref::<5>(), None);
+    }
+
+    #[test]
+    fn test_last_chunk() {
+        let blob: Blob<8> = Blob::from_slice(&[1, 2, 3, 4]);
+        assert_eq!(blob.last_chunk::<2>(), Some(&[3u8, 4]));
+        // Exactly the valid length.
+        assert_eq!(blob.last_chunk::<4>(), Some(&[1u8, 2, 3, 4]));
+        // Fewer valid bytes than requested, even though the capacity is larger.
+        assert_eq!(blob.last_chunk::<5>(), None);
+        assert_eq!(Blob::<8>::new().last_chunk::<0>(), Some(&[]));
+    }
 }
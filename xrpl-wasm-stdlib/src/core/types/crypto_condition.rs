@@ -14,6 +14,9 @@ impl From<[u8; 32]> for Condition {
     }
 }
 
+/// Largest fulfillment rippled accepts, in bytes.
+pub const FULFILLMENT_MAX_SIZE: usize = 256;
+
 /// A crypto-condition Fulfillment. Note that from rippled source, this value is currently capped
 /// at 256 bytes, which allows us to treat it as such.
 ///
@@ -25,7 +28,7 @@ impl From<[u8; 32]> for Condition {
 /// Note: `Copy` is intentionally not derived due to the struct's size (256+ bytes).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Fulfillment {
-    pub data: [u8; 256],
+    pub data: [u8; FULFILLMENT_MAX_SIZE],
 
     /// The actual length of this Fulfillment, if less than data.len()
     pub len: usize,
@@ -1,6 +1,19 @@
 pub const CURRENCY_SIZE: usize = 20;
 pub const STANDARD_CURRENCY_SIZE: usize = 3; // For standard currencies like USD, EUR, etc.
 
+/// First byte of every AMM LP-token currency code.
+pub const LP_TOKEN_CURRENCY_PREFIX: u8 = 0x03;
+
+/// Size of an issued amount's serialization: 8-byte value, currency code, then issuer.
+const ISSUED_AMOUNT_SIZE: usize = 48;
+
+/// Offset of the currency code within an issued amount's serialization.
+const ISSUED_AMOUNT_CURRENCY_OFFSET: usize = 8;
+
+/// Characters rippled accepts in a 3-character standard currency code.
+const ISO_CHARSET: &[u8] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789<>(){}[]|?!@#$%^&*";
+
 /// Represents a currency code in the XRPL, which is a 20-byte identifier.
 ///
 /// Currency codes in XRPL can be either:
@@ -26,6 +39,85 @@ impl Currency {
     pub fn as_bytes(&self) -> &[u8; CURRENCY_SIZE] {
         &self.0
     }
+
+    /// Returns the canonical 20-byte code, as it appears in serialized amounts and issues.
+    pub fn to_bytes(&self) -> [u8; CURRENCY_SIZE] {
+        self.0
+    }
+
+    /// Extracts the currency code from a 48-byte issued (IOU) amount serialization, without
+    /// decoding the value or the issuer.
+    ///
+    /// Returns `None` if `bytes` is not 48 bytes long or does not have the issued-amount type bit
+    /// (the most significant bit) set.
+    pub fn from_amount_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != ISSUED_AMOUNT_SIZE || bytes[0] & 0x80 == 0 {
+            return None;
+        }
+        let mut code = [0u8; CURRENCY_SIZE];
+        code.copy_from_slice(
+            &bytes[ISSUED_AMOUNT_CURRENCY_OFFSET..ISSUED_AMOUNT_CURRENCY_OFFSET + CURRENCY_SIZE],
+        );
+        Some(Currency(code))
+    }
+
+    /// Returns `true` if this code may be used as the currency of an issued (IOU) amount.
+    ///
+    /// The following are rejected:
+    /// - All zeros, which denotes XRP itself
+    /// - `0x00..01`, rippled's "no currency" sentinel
+    /// - The standard-format code `"XRP"`, which is reserved and may not be issued
+    /// - Malformed standard codes: a leading `0x00` byte with non-zero bytes outside positions
+    ///   12-14, or characters outside rippled's ISO code character set
+    ///
+    /// Any code whose first byte is non-zero is treated as a non-standard (hex) code and accepted.
+    pub fn is_valid_issued(&self) -> bool {
+        if self.0[0] != 0 {
+            return true;
+        }
+        // A leading zero byte means a standard code; this also rejects XRP and "no currency".
+        self.is_standard() && &self.0[12..15] != b"XRP"
+    }
+
+    /// Returns `true` if this is a standard (ISO-style) currency code: zero everywhere except
+    /// bytes 12-14, which hold three characters from rippled's ISO code character set.
+    ///
+    /// A non-standard code is not misread as standard just because its other bytes happen to
+    /// be zero; bytes 12-14 must be valid characters too, so all-zero XRP is not standard. The
+    /// reserved `"XRP"` code has the standard layout and is reported as standard.
+    pub fn is_standard(&self) -> bool {
+        let bytes = &self.0;
+        bytes[..12].iter().all(|b| *b == 0)
+            && bytes[15..].iter().all(|b| *b == 0)
+            && bytes[12..15].iter().all(|c| ISO_CHARSET.contains(c))
+    }
+
+    /// Returns the 3-character code of a standard currency (see [`Currency::is_standard`]), or
+    /// `None` for any other code.
+    pub fn to_iso(&self) -> Option<&str> {
+        if !self.is_standard() {
+            return None;
+        }
+        // ISO_CHARSET is ASCII, so this cannot fail.
+        core::str::from_utf8(&self.0[12..15]).ok()
+    }
+
+    /// Returns `true` if this is the currency code of an AMM liquidity provider (LP) token.
+    ///
+    /// An AMM issues its LP tokens under a non-standard code derived from the pool's two assets:
+    ///
+    /// ```text
+    /// byte  0      : 0x03 (LP_TOKEN_CURRENCY_PREFIX)
+    /// bytes 1..20  : the first 19 bytes of SHA-512Half(min(currency1, currency2) ||
+    ///                max(currency1, currency2))
+    /// ```
+    ///
+    /// Only the prefix can be checked without knowing the pool's assets, so a hex code that
+    /// happens to start with `0x03` is also reported as an LP token. The issuer of an LP token
+    /// is always the AMM's own account.
+    pub fn is_lp_token(&self) -> bool {
+        self.0[0] == LP_TOKEN_CURRENCY_PREFIX
+    }
 }
 
 impl From<[u8; CURRENCY_SIZE]> for Currency {
@@ -98,4 +190,140 @@ mod tests {
         // Verify the bytes
         assert_eq!(currency.as_bytes(), &expected);
     }
+
+    #[test]
+    fn test_is_valid_issued_accepts_standard_and_hex_codes() {
+        assert!(Currency::from(*b"USD").is_valid_issued());
+        assert!(Currency::from(*b"eu$").is_valid_issued());
+
+        let mut hex = [0xABu8; CURRENCY_SIZE];
+        hex[0] = 0x03; // e.g. an AMM LP token code
+        assert!(Currency::from(hex).is_valid_issued());
+    }
+
+    #[test]
+    fn test_is_valid_issued_rejects_forbidden_codes() {
+        // XRP itself
+        assert!(!Currency::from([0u8; CURRENCY_SIZE]).is_valid_issued());
+
+        // The "no currency" sentinel
+        let mut no_currency = [0u8; CURRENCY_SIZE];
+        no_currency[19] = 1;
+        assert!(!Currency::from(no_currency).is_valid_issued());
+
+        // The reserved "XRP" standard code
+        assert!(!Currency::from(*b"XRP").is_valid_issued());
+
+        // Non-zero bytes outside the standard code position
+        let mut stray_prefix = *Currency::from(*b"USD").as_bytes();
+        stray_prefix[5] = 1;
+        assert!(!Currency::from(stray_prefix).is_valid_issued());
+        let mut stray_suffix = *Currency::from(*b"USD").as_bytes();
+        stray_suffix[16] = 1;
+        assert!(!Currency::from(stray_suffix).is_valid_issued());
+
+        // Characters outside the ISO character set
+        assert!(!Currency::from(*b"U D").is_valid_issued());
+        assert!(!Currency::from([b'U', b'S', 0]).is_valid_issued());
+    }
+
+    #[test]
+    fn test_is_standard_and_to_iso() {
+        let usd = Currency::from(*b"USD");
+        assert!(usd.is_standard());
+        assert_eq!(usd.to_iso(), Some("USD"));
+
+        let xrp = Currency::from(*b"XRP");
+        assert!(xrp.is_standard());
+        assert_eq!(xrp.to_iso(), Some("XRP"));
+
+        assert!(!Currency::from([0u8; CURRENCY_SIZE]).is_standard());
+        assert_eq!(Currency::from([0u8; CURRENCY_SIZE]).to_iso(), None);
+    }
+
+    #[test]
+    fn test_non_standard_code_with_zeros_is_not_iso() {
+        // A hex code that is zero in the ISO positions, and everywhere but its first byte.
+        let mut tricky = [0u8; CURRENCY_SIZE];
+        tricky[0] = 0x01;
+        assert!(!Currency::from(tricky).is_standard());
+        assert_eq!(Currency::from(tricky).to_iso(), None);
+
+        // Zero outside the ISO positions, but with non-character bytes inside them.
+        let mut tricky = [0u8; CURRENCY_SIZE];
+        tricky[12..15].copy_from_slice(&[b'U', 0x00, b'D']);
+        assert!(!Currency::from(tricky).is_standard());
+        assert_eq!(Currency::from(tricky).to_iso(), None);
+
+        // A valid ISO triple, but with a stray byte elsewhere.
+        let mut tricky = [0u8; CURRENCY_SIZE];
+        tricky[12..15].copy_from_slice(b"USD");
+        tricky[19] = 0x01;
+        assert!(!Currency::from(tricky).is_standard());
+        assert_eq!(Currency::from(tricky).to_iso(), None);
+    }
+
+    #[test]
+    fn test_is_lp_token() {
+        // LPTokenBalance currency of the XRP/TST pool from the XRPL documentation's AMM example.
+        let lp_token = Currency::from(
+            crate::decode_hex_20(b"039C99CD9AB0B70B32ECDA51EAAE471625608EA2").unwrap(),
+        );
+        assert!(lp_token.is_lp_token());
+        assert!(lp_token.is_valid_issued());
+
+        assert!(!Currency::from(*b"USD").is_lp_token());
+        assert!(!Currency::from([0u8; CURRENCY_SIZE]).is_lp_token());
+        let mut other_hex = [0xABu8; CURRENCY_SIZE];
+        other_hex[0] = 0x01;
+        assert!(!Currency::from(other_hex).is_lp_token());
+    }
+
+    fn issued_amount_bytes(currency: &Currency) -> [u8; ISSUED_AMOUNT_SIZE] {
+        let mut bytes = [0u8; ISSUED_AMOUNT_SIZE];
+        // 1 USD: positive issued amount, mantissa 10^15, exponent -15.
+        bytes[..8].copy_from_slice(&0xD4838D7EA4C68000u64.to_be_bytes());
+        bytes[8..28].copy_from_slice(&currency.to_bytes());
+        bytes[28..].copy_from_slice(&[0x11; 20]);
+        bytes
+    }
+
+    #[test]
+    fn test_to_bytes_standard_and_non_standard() {
+        let mut expected = [0u8; CURRENCY_SIZE];
+        expected[12..15].copy_from_slice(b"USD");
+        assert_eq!(Currency::from(*b"USD").to_bytes(), expected);
+
+        let hex = crate::decode_hex_20(b"039C99CD9AB0B70B32ECDA51EAAE471625608EA2").unwrap();
+        assert_eq!(Currency::from(hex).to_bytes(), hex);
+    }
+
+    #[test]
+    fn test_from_amount_bytes_round_trips() {
+        let usd = Currency::from(*b"USD");
+        assert_eq!(
+            Currency::from_amount_bytes(&issued_amount_bytes(&usd)),
+            Some(usd)
+        );
+
+        let hex = Currency::from(
+            crate::decode_hex_20(b"039C99CD9AB0B70B32ECDA51EAAE471625608EA2").unwrap(),
+        );
+        assert_eq!(
+            Currency::from_amount_bytes(&issued_amount_bytes(&hex)),
+            Some(hex)
+        );
+    }
+
+    #[test]
+    fn test_from_amount_bytes_rejects_other_encodings() {
+        let bytes = issued_amount_bytes(&Currency::from(*b"USD"));
+        // Wrong length, e.g. an XRP amount or a truncated buffer.
+        assert_eq!(Currency::from_amount_bytes(&bytes[..8]), None);
+        assert_eq!(Currency::from_amount_bytes(&bytes[..47]), None);
+        // Type bit clear: not an issued amount.
+        let mut not_issued = bytes;
+        not_issued[0] &= 0x7F;
+        assert_eq!(Currency::from_amount_bytes(&not_issued), None);
+    }
 }
@@ -1,7 +1,7 @@
 use crate::core::types::account_id::AccountID;
 use crate::core::types::currency::Currency;
 use crate::core::types::mpt_id::MptId;
-use crate::host::Result;
+use crate::host::{Error, Result};
 
 /// Struct to represent an Issue of type XRP. Exists so that other structs can restrict type
 /// information to XRP in their declarations (this is not possible with just the `Issue` enum below).
@@ -43,6 +43,16 @@ impl IouIssue {
         }
     }
 
+    /// Creates an `IouIssue`, rejecting currency codes that cannot be issued.
+    ///
+    /// Returns `Error::InvalidParams` if `currency` fails [`Currency::is_valid_issued`].
+    pub fn try_new(issuer: AccountID, currency: Currency) -> Result<Self> {
+        if !currency.is_valid_issued() {
+            return Result::Err(Error::InvalidParams);
+        }
+        Result::Ok(Self::new(issuer, currency))
+    }
+
     pub fn as_bytes(&self) -> &[u8] {
         &self._bytes
     }
@@ -135,3 +145,38 @@ impl Issue {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::INVALID_PARAMS;
+
+    #[test]
+    fn test_iou_issue_try_new_accepts_valid_currency() {
+        let issuer = AccountID::from([1u8; 20]);
+        let currency = Currency::from(*b"USD");
+        let issue = IouIssue::try_new(issuer, currency).unwrap();
+        assert_eq!(issue, IouIssue::new(issuer, currency));
+        assert_eq!(&issue.as_bytes()[..20], currency.as_bytes());
+        assert_eq!(&issue.as_bytes()[20..], &[1u8; 20]);
+    }
+
+    #[test]
+    fn test_iou_issue_try_new_rejects_xrp_and_zero_currency() {
+        let issuer = AccountID::from([1u8; 20]);
+        assert_eq!(
+            IouIssue::try_new(issuer, Currency::from(*b"XRP"))
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+        assert_eq!(
+            IouIssue::try_new(issuer, Currency::from([0u8; 20]))
+                .err()
+                .unwrap()
+                .code(),
+            INVALID_PARAMS
+        );
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(test)]
+use crate::core::types::account_id::ACCOUNT_ID_SIZE;
 use crate::core::types::account_id::AccountID;
 use crate::core::types::currency::Currency;
 use crate::core::types::issue::Issue;
@@ -456,6 +458,24 @@ pub fn escrow_keylet(owner: &AccountID, seq: i32) -> Result<KeyletBytes> {
     })
 }
 
+/// Ledger space key of escrow objects (`'u'`).
+#[cfg(test)]
+const ESCROW_SPACE: u16 = b'u' as u16;
+
+/// Returns the preimage the host hashes for [`escrow_keylet`]: `0x0075 || owner || sequence`.
+/// The test host derives escrow keylets from it, so they match rippled's.
+#[cfg(test)]
+pub(crate) fn escrow_keylet_preimage(
+    owner: &AccountID,
+    sequence: u32,
+) -> [u8; 2 + ACCOUNT_ID_SIZE + 4] {
+    let mut preimage = [0u8; 2 + ACCOUNT_ID_SIZE + 4];
+    preimage[..2].copy_from_slice(&ESCROW_SPACE.to_be_bytes());
+    preimage[2..2 + ACCOUNT_ID_SIZE].copy_from_slice(owner.as_bytes());
+    preimage[2 + ACCOUNT_ID_SIZE..].copy_from_slice(&sequence.to_be_bytes());
+    preimage
+}
+
 /// Generates a trustline keylet for a given pair of accounts and currency code.
 ///
 /// A trustline keylet is used to reference trustline entries in the XRP Ledger.
@@ -1078,6 +1098,19 @@ pub fn vault_keylet(account: &AccountID, seq: i32) -> Result<KeyletBytes> {
     })
 }
 
+/// Computes the SHA-512Half of `data` with the host, for keys the host has no keylet function
+/// for or whose preimage the caller builds itself.
+pub(crate) fn sha512_half(data: &[u8]) -> Result<KeyletBytes> {
+    create_keylet_from_host_call(|keylet_buffer_ptr, keylet_buffer_len| unsafe {
+        host::compute_sha512_half(
+            data.as_ptr(),
+            data.len(),
+            keylet_buffer_ptr,
+            keylet_buffer_len,
+        )
+    })
+}
+
 /// Generic helper function to create a keylet by calling a host function.
 ///
 /// This function handles the common tasks of:
@@ -1099,3 +1132,26 @@ where
 
     match_result_code_with_expected_bytes(result_code, XRPL_KEYLET_SIZE, || keylet_buffer)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escrow_keylet_vector() {
+        // The escrow created by the genesis account's EscrowCreate with Sequence 1.
+        let owner = AccountID(crate::r_address!("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh"));
+        let preimage = escrow_keylet_preimage(&owner, 1);
+        assert_eq!(&preimage[..2], b"\x00u");
+        assert_eq!(&preimage[2..22], owner.as_bytes());
+        assert_eq!(&preimage[22..], &[0, 0, 0, 1]);
+
+        assert_eq!(
+            escrow_keylet(&owner, 1).unwrap(),
+            crate::decode_hex_32(
+                b"BB86354D83FCF380224E9BBF88125E80CEA16460D2B1E140132A822D3EB17B27"
+            )
+            .unwrap()
+        );
+    }
+}
@@ -13,6 +13,46 @@ pub mod nft;
 pub mod object;
 pub mod opaque_float;
 pub mod public_key;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod secret_key;
+#[cfg(all(feature = "serde", not(target_arch = "wasm32")))]
+mod serde_support;
 pub mod signature;
 pub mod transaction_type;
 pub mod uint;
+
+#[cfg(test)]
+pub(crate) mod test_fmt {
+    //! A fixed-buffer [`core::fmt::Write`] sink for checking formatted output in `no_std` tests.
+
+    pub(crate) struct SliceWriter<'a> {
+        buf: &'a mut [u8],
+        len: usize,
+    }
+
+    impl<'a> SliceWriter<'a> {
+        pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+            SliceWriter { buf, len: 0 }
+        }
+
+        /// Returns everything written so far.
+        pub(crate) fn into_str(self) -> &'a str {
+            core::str::from_utf8(&self.buf[..self.len]).unwrap()
+        }
+    }
+
+    impl core::fmt::Write for SliceWriter<'_> {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.buf[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    /// Formats `args` into `out` and returns the written text.
+    pub(crate) fn format<'a>(out: &'a mut [u8], args: core::fmt::Arguments<'_>) -> &'a str {
+        let mut writer = SliceWriter::new(out);
+        core::fmt::write(&mut writer, args).unwrap();
+        writer.into_str()
+    }
+}
@@ -18,6 +18,10 @@ pub const MPT_SEQUENCE_NUM_SIZE: usize = 4;
 #[repr(C)]
 pub struct MptId([u8; MPT_ID_SIZE]);
 
+/// The name the XRPL protocol uses for an [`MptId`], as in the `MPTokenIssuanceID` field and the
+/// `mpt_issuance_id` key of JSON amounts. Both name the same 24-byte value.
+pub type MptIssuanceId = MptId;
+
 impl MptId {
     /// Creates a new MptId from a sequence number and an issuer account ID.
     pub fn new(sequence_num: u32, issuer: AccountID) -> Self {
@@ -131,4 +135,32 @@ mod tests {
         assert_eq!(mpt_id.get_sequence_num(), sequence_num);
         assert_eq!(mpt_id.get_issuer(), account_id);
     }
+
+    #[test]
+    fn test_mpt_issuance_id_round_trips() {
+        let issuer = AccountID::from([9u8; 20]);
+        let id = MptIssuanceId::new(0x0102_0304, issuer);
+        assert_eq!(&id.as_bytes()[..4], &[1, 2, 3, 4]);
+        assert_eq!(MptIssuanceId::from(*id.as_bytes()), id);
+        assert_eq!(
+            MptIssuanceId::new(id.get_sequence_num(), id.get_issuer()),
+            id
+        );
+    }
+
+    #[test]
+    fn test_mpt_issuance_id_round_trips_through_amount() {
+        use crate::core::types::amount::Amount;
+
+        let id = MptIssuanceId::new(77, AccountID::from([4u8; 20]));
+        let amount = Amount::MPT {
+            num_units: 500,
+            is_positive: true,
+            mpt_id: id,
+        };
+        let (bytes, _) = amount.to_stamount_bytes();
+        let decoded = Amount::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.mpt_issuance_id(), Some(id));
+        assert_eq!(Amount::XRP { num_drops: 1 }.mpt_issuance_id(), None);
+    }
 }
@@ -28,6 +28,12 @@ pub const NFT_ID_SIZE: usize = 32;
 /// Maximum size for NFT URI data (256 bytes)
 pub const NFT_URI_MAX_SIZE: usize = 256;
 
+/// Multiplier of the generator that scrambles the taxon stored in an NFTokenID.
+const TAXON_CIPHER_MULTIPLIER: u32 = 384_160_001;
+
+/// Increment of the generator that scrambles the taxon stored in an NFTokenID.
+const TAXON_CIPHER_INCREMENT: u32 = 2_459;
+
 /// NFToken flags - see [NFToken documentation](https://xrpl.org/docs/references/protocol/data-types/nftoken)
 pub mod flags {
     /// The issuer (or an entity authorized by the issuer) may destroy the object.
@@ -47,6 +53,12 @@ pub mod flags {
     /// If set, indicates that the minted token may be transferred to others.
     /// If not set, the token can only be transferred back to the issuer.
     pub const TRANSFERABLE: u16 = 0x0008;
+
+    /// If set, the token's URI may be changed by the issuer (DynamicNFT amendment).
+    pub const MUTABLE: u16 = 0x0010;
+
+    /// Flag bits that no amendment assigns; a well-formed NFTokenID has none of them set.
+    pub const RESERVED_MASK: u16 = !(BURNABLE | ONLY_XRP | TRUST_LINE | TRANSFERABLE | MUTABLE);
 }
 
 /// A wrapper around NFToken flags that provides efficient helper methods.
@@ -156,6 +168,21 @@ impl NFToken {
         NFToken(id)
     }
 
+    /// Creates an NFToken from a 32-byte identifier after checking that it is plausibly real.
+    ///
+    /// Use this for IDs from untrusted input, such as a memo, to reject garbage before making any
+    /// host calls; [`NFToken::new`] remains for trusted sources. The check is structural only
+    /// and does not prove the token exists. It returns `Error::InvalidParams` if:
+    /// - Any [`flags::RESERVED_MASK`] bit is set
+    /// - The issuer (bytes 4-23) is all zeros
+    pub fn new_checked(id: [u8; NFT_ID_SIZE]) -> Result<Self> {
+        let flags = u16::from_be_bytes([id[0], id[1]]);
+        if flags & flags::RESERVED_MASK != 0 || id[4..4 + ACCOUNT_ID_SIZE].iter().all(|b| *b == 0) {
+            return Result::Err(Error::InvalidParams);
+        }
+        Result::Ok(NFToken(id))
+    }
+
     /// Returns the raw NFTokenID as a byte array.
     ///
     #[inline]
@@ -244,6 +271,20 @@ impl NFToken {
         }
     }
 
+    /// Decodes the issuer account from bytes 4-23 of the NFTokenID, without calling the host.
+    ///
+    /// This reads the same bytes as [`NFToken::issuer`] and never fails.
+    #[inline]
+    pub const fn issuer_local(&self) -> AccountID {
+        let mut issuer = [0u8; ACCOUNT_ID_SIZE];
+        let mut i = 0;
+        while i < ACCOUNT_ID_SIZE {
+            issuer[i] = self.0[4 + i];
+            i += 1;
+        }
+        AccountID(issuer)
+    }
+
     /// Retrieves the taxon of this NFToken.
     ///
     /// The taxon is an issuer-defined value that groups related NFTs together.
@@ -273,6 +314,41 @@ impl NFToken {
         }
     }
 
+    /// Decodes the taxon from bytes 24-27 of the NFTokenID, without calling the host.
+    ///
+    /// The ledger stores the taxon XOR-ed with a linear congruential value derived from the token
+    /// sequence (bytes 28-31), so that consecutive tokens with the same taxon do not share a
+    /// prefix; this undoes that scramble and returns the value the issuer chose at mint time.
+    #[inline]
+    pub const fn taxon_local(&self) -> u32 {
+        let sequence = u32::from_be_bytes([self.0[28], self.0[29], self.0[30], self.0[31]]);
+        let scrambled = u32::from_be_bytes([self.0[24], self.0[25], self.0[26], self.0[27]]);
+        scrambled
+            ^ TAXON_CIPHER_MULTIPLIER
+                .wrapping_mul(sequence)
+                .wrapping_add(TAXON_CIPHER_INCREMENT)
+    }
+
+    /// Decodes the token sequence (serial) from bytes 28-31 of the NFTokenID, without calling
+    /// the host.
+    #[inline]
+    pub const fn token_sequence_local(&self) -> u32 {
+        u32::from_be_bytes([self.0[28], self.0[29], self.0[30], self.0[31]])
+    }
+
+    /// Returns `true` if this token's serial could have been assigned by an issuer that has
+    /// used `issuer_minted_count` serials so far, i.e. the serial is less than that count.
+    ///
+    /// An issuer's serials start at its `FirstNFTokenSequence` (zero on accounts that predate
+    /// fixNFTokenRemint) and advance by one per mint, so pass `FirstNFTokenSequence +
+    /// MintedNFTokens` from the issuer's AccountRoot;
+    /// [`issuer_minted_count`](crate::core::ledger_objects::nft::issuer_minted_count) reads it.
+    /// A serial at or above that value was never minted, so the NFTokenID is forged.
+    #[inline]
+    pub const fn serial_within(&self, issuer_minted_count: u32) -> bool {
+        self.token_sequence_local() < issuer_minted_count
+    }
+
     /// Retrieves the token sequence number of this NFToken.
     ///
     /// The token sequence number is automatically incremented for each NFToken minted
@@ -321,8 +397,8 @@ impl NFToken {
         let mut uri_buf = [0u8; NFT_URI_MAX_SIZE];
         let result = unsafe {
             host::get_nft(
-                owner.0.as_ptr(),
-                owner.0.len(),
+                owner.as_ptr(),
+                owner.len(),
                 self.as_ptr(),
                 self.len(),
                 uri_buf.as_mut_ptr(),
@@ -343,6 +419,18 @@ impl From<[u8; NFT_ID_SIZE]> for NFToken {
     }
 }
 
+impl From<&[u8; NFT_ID_SIZE]> for NFToken {
+    fn from(value: &[u8; NFT_ID_SIZE]) -> Self {
+        NFToken(*value)
+    }
+}
+
+impl From<NFToken> for [u8; NFT_ID_SIZE] {
+    fn from(value: NFToken) -> Self {
+        value.0
+    }
+}
+
 impl AsRef<[u8]> for NFToken {
     fn as_ref(&self) -> &[u8] {
         &self.0
@@ -352,6 +440,7 @@ impl AsRef<[u8]> for NFToken {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::NFT;
 
     #[test]
     fn test_nft_creation() {
@@ -361,6 +450,88 @@ mod tests {
         assert_eq!(nft.len(), 32);
     }
 
+    #[test]
+    fn test_new_checked_accepts_real_id() {
+        // The example NFTokenID from the module documentation.
+        let id = crate::decode_hex_32(
+            b"000B0539C35B55AA096BA6D87A6E6C965A6534150DC56E5E12C5D09E0000000C",
+        )
+        .unwrap();
+        assert_eq!(NFToken::new_checked(id).unwrap(), NFToken::new(id));
+
+        let mut mutable = id;
+        mutable[1] |= flags::MUTABLE as u8;
+        assert!(NFToken::new_checked(mutable).is_ok());
+    }
+
+    #[test]
+    fn test_new_checked_rejects_corrupt_ids() {
+        use crate::host::error_codes::INVALID_PARAMS;
+
+        assert_eq!(
+            NFToken::new_checked([0u8; 32]).err().unwrap().code(),
+            INVALID_PARAMS
+        );
+
+        let mut reserved = [0xAAu8; 32];
+        reserved[0] = 0x80;
+        reserved[1] = 0x00;
+        assert_eq!(
+            NFToken::new_checked(reserved).err().unwrap().code(),
+            INVALID_PARAMS
+        );
+        reserved[0] = 0x00;
+        reserved[1] = 0x20;
+        assert_eq!(
+            NFToken::new_checked(reserved).err().unwrap().code(),
+            INVALID_PARAMS
+        );
+    }
+
+    #[test]
+    fn test_issuer_local() {
+        let id = crate::decode_hex_32(
+            b"000B0539C35B55AA096BA6D87A6E6C965A6534150DC56E5E12C5D09E0000000C",
+        )
+        .unwrap();
+        let expected = crate::decode_hex_20(b"C35B55AA096BA6D87A6E6C965A6534150DC56E5E").unwrap();
+        assert_eq!(NFToken::new(id).issuer_local(), AccountID(expected));
+    }
+
+    #[test]
+    fn test_taxon_local_unscrambles_documented_example() {
+        // The module-level example: scrambled taxon 12C5D09E with sequence 0000000C.
+        let id = crate::decode_hex_32(
+            b"000B0539C35B55AA096BA6D87A6E6C965A6534150DC56E5E12C5D09E0000000C",
+        )
+        .unwrap();
+        assert_eq!(NFToken::new(id).taxon_local(), 1337);
+    }
+
+    #[test]
+    fn test_taxon_local_sequence_zero() {
+        // With sequence 0 the scramble is a plain XOR with the increment.
+        let mut id = [0u8; NFT_ID_SIZE];
+        id[24..28].copy_from_slice(&(7u32 ^ 2_459).to_be_bytes());
+        assert_eq!(NFToken::new(id).taxon_local(), 7);
+    }
+
+    #[test]
+    fn test_serial_within() {
+        // The module-level example has serial 0x0C.
+        let nft = NFToken::new(
+            crate::decode_hex_32(
+                b"000B0539C35B55AA096BA6D87A6E6C965A6534150DC56E5E12C5D09E0000000C",
+            )
+            .unwrap(),
+        );
+        assert_eq!(nft.token_sequence_local(), 12);
+        assert!(!nft.serial_within(0));
+        assert!(!nft.serial_within(12));
+        assert!(nft.serial_within(13));
+        assert!(nft.serial_within(u32::MAX));
+    }
+
     #[test]
     fn test_nft_from_array() {
         let nft_id = [0u8; 32];
@@ -593,4 +764,14 @@ mod tests {
         let uri = result.unwrap();
         assert!(uri.len <= NFT_URI_MAX_SIZE);
     }
+
+    #[test]
+    fn test_nft_alias_round_trip() {
+        let bytes = [0xABu8; NFT_ID_SIZE];
+        let nft: NFT = NFT::from(&bytes);
+        assert_eq!(nft, NFToken::new(bytes));
+
+        let back: [u8; NFT_ID_SIZE] = nft.into();
+        assert_eq!(back, bytes);
+    }
 }
@@ -0,0 +1,82 @@
+//! Secret key material for host-side tooling.
+//!
+//! Contracts never hold private keys, so this module is only available on non-wasm targets.
+//! [`SecretKey`] is deliberately more guarded than the public identifier types such as
+//! `AccountID`: its bytes are wiped when it is dropped and it cannot be printed.
+
+use core::fmt;
+use core::sync::atomic::{Ordering, compiler_fence};
+
+pub const SECRET_KEY_SIZE: usize = 32;
+
+/// A 32-byte private key (a secp256k1 scalar or an Ed25519 seed).
+///
+/// - The bytes are overwritten with zeros when the key is dropped, including every clone.
+/// - `Debug` prints `SecretKey(<redacted>)`, and `Display` is not implemented, so the key cannot
+///   end up in a log or a trace by accident. Use [`SecretKey::expose_secret`] where the raw
+///   bytes are genuinely needed.
+///
+/// Note: `Copy` is intentionally not derived; an implicit copy would escape the wipe on drop.
+/// Neither is `PartialEq`: a derived comparison stops at the first differing byte, so its timing
+/// would leak how much of a guessed key is right.
+#[derive(Clone)]
+pub struct SecretKey([u8; SECRET_KEY_SIZE]);
+
+impl SecretKey {
+    /// Creates a key from its raw bytes.
+    pub fn from_bytes(bytes: [u8; SECRET_KEY_SIZE]) -> Self {
+        SecretKey(bytes)
+    }
+
+    /// Returns the raw key bytes. Avoid copying them anywhere that outlives the key.
+    pub fn expose_secret(&self) -> &[u8; SECRET_KEY_SIZE] {
+        &self.0
+    }
+
+    /// Overwrites the key with zeros. Called automatically on drop.
+    pub fn zeroize(&mut self) {
+        for byte in self.0.iter_mut() {
+            // Volatile writes, so the wipe is not optimized away as a dead store.
+            unsafe { core::ptr::write_volatile(byte, 0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretKey(<redacted>)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::test_fmt;
+
+    #[test]
+    fn test_debug_is_redacted() {
+        let key = SecretKey::from_bytes([0xAB; SECRET_KEY_SIZE]);
+        let mut out = [0u8; 64];
+        assert_eq!(
+            test_fmt::format(&mut out, format_args!("{key:?}")),
+            "SecretKey(<redacted>)"
+        );
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let mut key = SecretKey::from_bytes([7; SECRET_KEY_SIZE]);
+        let copy = key.clone();
+        key.zeroize();
+        assert_eq!(key.expose_secret(), &[0; SECRET_KEY_SIZE]);
+        // Clones own their bytes and are wiped independently.
+        assert_eq!(copy.expose_secret(), &[7; SECRET_KEY_SIZE]);
+    }
+}
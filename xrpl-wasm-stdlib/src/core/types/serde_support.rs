@@ -0,0 +1,532 @@
+//! # Serde Support
+//!
+//! `serde` implementations for core types, enabled by the `serde` feature on non-wasm targets
+//! only. They exist for host-side tooling (test fixtures, simulators, configuration files) and
+//! are never compiled into contracts. Nothing here allocates, so `serde` is used without `std`.
+//!
+//! Forms, chosen to match the JSON used by rippled where one exists:
+//!
+//! - [`Blob`], [`UInt`] (including `Hash256`) and [`NFToken`]: uppercase hex strings
+//! - [`AccountID`]: 40 hex characters; deserialization also accepts a classic `r...` address
+//! - [`Amount`]: XRP as a string of drops (`"1000000"`), issued amounts as
+//!   `{"value", "currency", "issuer"}` and MPT amounts as `{"value", "mpt_issuance_id"}`
+
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::core::constants::MAX_DROPS;
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::amount::{Amount, write_iou_decimal};
+use crate::core::types::blob::Blob;
+use crate::core::types::currency::Currency;
+use crate::core::types::mpt_id::{MPT_ID_SIZE, MptId};
+use crate::core::types::nft::{NFT_ID_SIZE, NFToken};
+use crate::core::types::uint::UInt;
+use crate::hex_char_to_nibble;
+use crate::host;
+
+/// Longest string accepted for a single amount value, currency code or issuer.
+const MAX_FIELD_TEXT: usize = 64;
+
+/// Formats bytes as uppercase hex.
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{b:02X}"))
+    }
+}
+
+/// Decodes `hex` into the front of `out`, returning the number of bytes written, or `None` if
+/// `hex` has odd length, a non-hex character, or does not fit.
+fn decode_hex(hex: &str, out: &mut [u8]) -> Option<usize> {
+    let hex = hex.as_bytes();
+    if hex.len() % 2 != 0 || hex.len() / 2 > out.len() {
+        return None;
+    }
+    for (byte, pair) in out.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (hex_char_to_nibble(pair[0])? << 4) | hex_char_to_nibble(pair[1])?;
+    }
+    Some(hex.len() / 2)
+}
+
+/// Decodes `hex` into exactly `N` bytes.
+fn decode_hex_exact<const N: usize, E: de::Error>(hex: &str) -> Result<[u8; N], E> {
+    let mut bytes = [0u8; N];
+    match decode_hex(hex, &mut bytes) {
+        Some(len) if len == N => Ok(bytes),
+        _ => Err(E::invalid_length(hex.len(), &"an even-length hex string")),
+    }
+}
+
+/// Deserializes a hex string through a conversion that returns `None` for invalid input. The
+/// first field describes the expected input for error messages.
+struct HexVisitor<T>(&'static str, fn(&str) -> Option<T>);
+
+impl<'de, T> Visitor<'de> for HexVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+        (self.1)(value).ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self.0))
+    }
+}
+
+/// A string copied into a fixed-size buffer, for reading map values without allocating.
+struct StackStr<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> StackStr<N> {
+    fn as_str(&self) -> &str {
+        // Only ever filled from a `&str`, cut at a length that was checked to fit.
+        unsafe { core::str::from_utf8_unchecked(&self.data[..self.len]) }
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for StackStr<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StackStrVisitor<const N: usize>;
+
+        impl<const N: usize> Visitor<'_> for StackStrVisitor<N> {
+            type Value = StackStr<N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a string of at most {N} bytes")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<StackStr<N>, E> {
+                if value.len() > N {
+                    return Err(E::invalid_length(value.len(), &self));
+                }
+                let mut data = [0u8; N];
+                data[..value.len()].copy_from_slice(value.as_bytes());
+                Ok(StackStr {
+                    data,
+                    len: value.len(),
+                })
+            }
+        }
+
+        deserializer.deserialize_str(StackStrVisitor)
+    }
+}
+
+/// Maps a host error from one of the parsing helpers to a serde error.
+fn parse_error<T, E: de::Error>(result: host::Result<T>, what: &'static str) -> Result<T, E> {
+    match result {
+        host::Result::Ok(value) => Ok(value),
+        host::Result::Err(e) => Err(E::custom(format_args!(
+            "invalid {what} (error {})",
+            e.code()
+        ))),
+    }
+}
+
+impl<const N: usize> Serialize for Blob<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Hex(self.as_slice()))
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Blob<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(HexVisitor("a hex string that fits the blob", |hex| {
+            let mut blob = Blob::new();
+            blob.len = decode_hex(hex, &mut blob.data)?;
+            Some(blob)
+        }))
+    }
+}
+
+impl<const N: usize> Serialize for UInt<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Hex(&self.0))
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for UInt<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(HexVisitor("a fixed-length hex string", |hex| {
+            decode_hex_exact::<N, de::value::Error>(hex).ok().map(UInt)
+        }))
+    }
+}
+
+impl Serialize for NFToken {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Hex(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for NFToken {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(HexVisitor("a 64-character hex NFTokenID", |hex| {
+            decode_hex_exact::<NFT_ID_SIZE, de::value::Error>(hex)
+                .ok()
+                .map(NFToken)
+        }))
+    }
+}
+
+/// Parses 40 hex characters or a classic `r...` address.
+fn parse_account(text: &str) -> Option<AccountID> {
+    if text.len() == ACCOUNT_ID_SIZE * 2 {
+        return decode_hex_exact::<ACCOUNT_ID_SIZE, de::value::Error>(text)
+            .ok()
+            .map(AccountID);
+    }
+    AccountID::from_classic_address(text).ok()
+}
+
+impl Serialize for AccountID {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&Hex(&self.0))
+    }
+}
+
+impl<'de> Deserialize<'de> for AccountID {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(HexVisitor(
+            "40 hex characters or a classic address",
+            parse_account,
+        ))
+    }
+}
+
+/// Formats an issued amount's value in decimal.
+struct IouValue<'a>(&'a crate::core::types::opaque_float::OpaqueFloat);
+
+impl fmt::Display for IouValue<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_iou_decimal(self.0, f)
+    }
+}
+
+/// Formats a currency as its 3-character code, or 40 hex characters for non-standard codes.
+struct CurrencyCode<'a>(&'a Currency);
+
+impl fmt::Display for CurrencyCode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.to_iso() {
+            Some(code) => f.write_str(code),
+            None => Hex(self.0.as_bytes()).fmt(f),
+        }
+    }
+}
+
+/// Formats an MPT amount's signed unit count.
+struct MptValue(u64, bool);
+
+impl fmt::Display for MptValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.1 || self.0 == 0 { "" } else { "-" };
+        write!(f, "{sign}{}", self.0)
+    }
+}
+
+impl Serialize for Amount {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Amount::XRP { num_drops } => serializer.collect_str(num_drops),
+            Amount::IOU {
+                amount,
+                issuer,
+                currency,
+            } => {
+                let mut map = serializer.serialize_map(Some(3))?;
+                map.serialize_entry("value", &format_args!("{}", IouValue(amount)))?;
+                map.serialize_entry("currency", &format_args!("{}", CurrencyCode(currency)))?;
+                map.serialize_entry("issuer", issuer)?;
+                map.end()
+            }
+            Amount::MPT {
+                num_units,
+                is_positive,
+                mpt_id,
+            } => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry(
+                    "value",
+                    &format_args!("{}", MptValue(*num_units, *is_positive)),
+                )?;
+                map.serialize_entry(
+                    "mpt_issuance_id",
+                    &format_args!("{}", Hex(mpt_id.as_bytes())),
+                )?;
+                map.end()
+            }
+        }
+    }
+}
+
+/// Keys of the structured amount forms.
+enum AmountKey {
+    Value,
+    Currency,
+    Issuer,
+    MptIssuanceId,
+}
+
+impl<'de> Deserialize<'de> for AmountKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const KEYS: &[&str] = &["value", "currency", "issuer", "mpt_issuance_id"];
+
+        struct KeyVisitor;
+
+        impl Visitor<'_> for KeyVisitor {
+            type Value = AmountKey;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an amount field name")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<AmountKey, E> {
+                match value {
+                    "value" => Ok(AmountKey::Value),
+                    "currency" => Ok(AmountKey::Currency),
+                    "issuer" => Ok(AmountKey::Issuer),
+                    "mpt_issuance_id" => Ok(AmountKey::MptIssuanceId),
+                    _ => Err(E::unknown_field(value, KEYS)),
+                }
+            }
+        }
+
+        deserializer.deserialize_identifier(KeyVisitor)
+    }
+}
+
+/// Parses an integer with an optional leading `-` into its magnitude and sign. Zero is always
+/// positive.
+fn parse_signed_integer(text: &str) -> Option<(u64, bool)> {
+    let (is_positive, digits) = match text.strip_prefix('-') {
+        Some(digits) => (false, digits),
+        None => (true, text),
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let num_units: u64 = digits.parse().ok()?;
+    Some((num_units, is_positive || num_units == 0))
+}
+
+struct AmountVisitor;
+
+impl<'de> Visitor<'de> for AmountVisitor {
+    type Value = Amount;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string of XRP drops or an issued or MPT amount object")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Amount, E> {
+        // The JSON form of XRP is an integer number of drops, never a decimal XRP value.
+        match parse_signed_integer(value) {
+            Some((drops, is_positive)) if drops <= MAX_DROPS => Ok(Amount::XRP {
+                num_drops: if is_positive {
+                    drops as i64
+                } else {
+                    -(drops as i64)
+                },
+            }),
+            _ => Err(E::invalid_value(
+                de::Unexpected::Str(value),
+                &"a number of drops",
+            )),
+        }
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Amount, A::Error> {
+        let mut value: Option<StackStr<MAX_FIELD_TEXT>> = None;
+        let mut currency: Option<StackStr<MAX_FIELD_TEXT>> = None;
+        let mut issuer: Option<StackStr<MAX_FIELD_TEXT>> = None;
+        let mut mpt_issuance_id: Option<StackStr<MAX_FIELD_TEXT>> = None;
+        while let Some(key) = map.next_key::<AmountKey>()? {
+            let (slot, name) = match key {
+                AmountKey::Value => (&mut value, "value"),
+                AmountKey::Currency => (&mut currency, "currency"),
+                AmountKey::Issuer => (&mut issuer, "issuer"),
+                AmountKey::MptIssuanceId => (&mut mpt_issuance_id, "mpt_issuance_id"),
+            };
+            if slot.is_some() {
+                return Err(de::Error::duplicate_field(name));
+            }
+            *slot = Some(map.next_value()?);
+        }
+
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        match (currency, issuer, mpt_issuance_id) {
+            (Some(currency), Some(issuer), None) => {
+                let issuer = parse_account(issuer.as_str()).ok_or_else(|| {
+                    de::Error::invalid_value(de::Unexpected::Str(issuer.as_str()), &"an issuer")
+                })?;
+                parse_error(
+                    Amount::parse_issued(value.as_str(), currency.as_str(), issuer),
+                    "issued amount",
+                )
+            }
+            (None, None, Some(id)) => {
+                let (num_units, is_positive) =
+                    parse_signed_integer(value.as_str()).ok_or_else(|| {
+                        de::Error::invalid_value(
+                            de::Unexpected::Str(value.as_str()),
+                            &"an integer MPT amount",
+                        )
+                    })?;
+                let mpt_id = decode_hex_exact::<MPT_ID_SIZE, A::Error>(id.as_str())?;
+                Ok(Amount::MPT {
+                    num_units,
+                    is_positive,
+                    mpt_id: MptId::from(mpt_id),
+                })
+            }
+            _ => Err(de::Error::custom(
+                "expected either currency and issuer, or mpt_issuance_id",
+            )),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AmountVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::opaque_float::{FLOAT_ONE, OpaqueFloat};
+    use crate::core::types::uint::Hash256;
+    use serde_test::{Token, assert_de_tokens_error, assert_tokens};
+
+    #[test]
+    fn test_blob_round_trip() {
+        let blob: Blob<8> = Blob::from_slice(&[0xDE, 0xAD, 0xBE]);
+        assert_tokens(&blob, &[Token::Str("DEADBE")]);
+
+        // More bytes than the blob can hold.
+        assert_de_tokens_error::<Blob<2>>(
+            &[Token::Str("DEADBE")],
+            "invalid value: string \"DEADBE\", expected a hex string that fits the blob",
+        );
+    }
+
+    #[test]
+    fn test_hash256_round_trip() {
+        let hash = Hash256::from([0xAB; 32]);
+        assert_tokens(
+            &hash,
+            &[Token::Str(
+                "ABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABABAB",
+            )],
+        );
+    }
+
+    #[test]
+    fn test_nftoken_round_trip() {
+        let token = NFToken([0x5A; 32]);
+        assert_tokens(
+            &token,
+            &[Token::Str(
+                "5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A5A",
+            )],
+        );
+    }
+
+    #[test]
+    fn test_account_id_round_trip() {
+        let account = AccountID([0x0A; 20]);
+        assert_tokens(
+            &account,
+            &[Token::Str("0A0A0A0A0A0A0A0A0A0A0A0A0A0A0A0A0A0A0A0A")],
+        );
+
+        // The genesis account, in classic form.
+        let genesis =
+            AccountID::from_classic_address("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh").unwrap();
+        serde_test::assert_de_tokens(
+            &genesis,
+            &[Token::Str("rHb9CJAWyB4rj91VRWn96DkukG4bwdtyTh")],
+        );
+    }
+
+    #[test]
+    fn test_xrp_amount_round_trip() {
+        let amount = Amount::XRP {
+            num_drops: -1_500_000,
+        };
+        assert_tokens(&amount, &[Token::Str("-1500000")]);
+
+        // Decimal XRP values are not drops.
+        assert_de_tokens_error::<Amount>(
+            &[Token::Str("1.5")],
+            "invalid value: string \"1.5\", expected a number of drops",
+        );
+    }
+
+    #[test]
+    fn test_iou_amount_round_trip() {
+        let amount = Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID([0x22; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert_tokens(
+            &amount,
+            &[
+                Token::Map { len: Some(3) },
+                Token::Str("value"),
+                Token::Str("1"),
+                Token::Str("currency"),
+                Token::Str("USD"),
+                Token::Str("issuer"),
+                Token::Str("2222222222222222222222222222222222222222"),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_mpt_amount_round_trip() {
+        let amount = Amount::MPT {
+            num_units: 42,
+            is_positive: false,
+            mpt_id: MptId::new(7, AccountID([0x33; 20])),
+        };
+        assert_tokens(
+            &amount,
+            &[
+                Token::Map { len: Some(2) },
+                Token::Str("value"),
+                Token::Str("-42"),
+                Token::Str("mpt_issuance_id"),
+                Token::Str("000000073333333333333333333333333333333333333333"),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_amount_rejects_mixed_forms() {
+        assert_de_tokens_error::<Amount>(
+            &[
+                Token::Map { len: None },
+                Token::Str("value"),
+                Token::Str("1"),
+                Token::Str("currency"),
+                Token::Str("USD"),
+                Token::Str("mpt_issuance_id"),
+                Token::Str("00"),
+                Token::MapEnd,
+            ],
+            "expected either currency and issuer, or mpt_issuance_id",
+        );
+    }
+}
@@ -13,11 +13,13 @@
 /// ## Derived Traits
 ///
 /// - `PartialEq, Eq`: Essential for comparisons and use in collections
+/// - `PartialOrd, Ord`: Compare values as big-endian unsigned integers, which is the order
+///   rippled uses to place keys in directories and NFToken pages
 /// - `Debug, Clone`: Standard traits for development and consistency
 ///
 /// Note: `Copy` is intentionally not derived because `N` can be arbitrarily large.
 #[repr(C)]
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UInt<const N: usize>(pub [u8; N]);
 
 impl<const N: usize> From<[u8; N]> for UInt<N> {
@@ -27,10 +29,39 @@ impl<const N: usize> From<[u8; N]> for UInt<N> {
 }
 
 impl<const N: usize> UInt<N> {
+    /// The all-zero value. Ledger fields use it to mean "none", for example an NFToken page link
+    /// with no page on that side.
+    pub const ZERO: Self = Self([0u8; N]);
+
+    /// Returns `true` if every byte is zero.
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.0.iter().all(|byte| *byte == 0)
+    }
+
     /// Returns the inner bytes as a reference to the inner array.
     pub fn as_bytes(&self) -> &[u8; N] {
         &self.0
     }
+
+    /// Returns the number of leading zero bits, treating the bytes as a big-endian integer.
+    ///
+    /// A value of all zeros returns `8 * N`.
+    pub fn leading_zero_bits(&self) -> u32 {
+        leading_zero_bits(&self.0)
+    }
+}
+
+/// Counts the leading zero bits of `bytes` interpreted as a big-endian integer.
+pub(crate) fn leading_zero_bits(bytes: &[u8]) -> u32 {
+    let mut count = 0;
+    for byte in bytes {
+        if *byte != 0 {
+            return count + byte.leading_zeros();
+        }
+        count += 8;
+    }
+    count
 }
 
 // Keep the existing constants for compatibility
@@ -409,4 +440,42 @@ mod tests {
         assert_eq!(uint2.as_bytes(), &[2u8; 16]);
         assert_eq!(uint3.as_bytes(), &[3u8; 16]);
     }
+
+    #[test]
+    fn test_uint_orders_as_big_endian_integer() {
+        let low = Hash256::from([0u8; 32]);
+        let mut mid_bytes = [0u8; 32];
+        mid_bytes[31] = 0xFF;
+        let mid = Hash256::from(mid_bytes);
+        let mut high_bytes = [0u8; 32];
+        high_bytes[0] = 0x01;
+        let high = Hash256::from(high_bytes);
+
+        assert!(low < mid);
+        // A single set bit in the most significant byte outranks a full least significant byte.
+        assert!(mid < high);
+        assert_eq!(high.cmp(&high.clone()), core::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_uint_leading_zero_bits() {
+        assert_eq!(Hash256::from([0u8; 32]).leading_zero_bits(), 256);
+        assert_eq!(Hash256::from([0xFFu8; 32]).leading_zero_bits(), 0);
+
+        let mut bytes = [0u8; 32];
+        bytes[2] = 0x10;
+        assert_eq!(Hash256::from(bytes).leading_zero_bits(), 16 + 3);
+        bytes[31] = 0x01;
+        assert_eq!(Hash256::from(bytes).leading_zero_bits(), 16 + 3);
+    }
+
+    #[test]
+    fn test_hash256_zero() {
+        assert!(Hash256::ZERO.is_zero());
+        assert_eq!(Hash256::ZERO, Hash256::from([0u8; 32]));
+
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        assert!(!Hash256::from(bytes).is_zero());
+    }
 }
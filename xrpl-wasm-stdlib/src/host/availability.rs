@@ -0,0 +1,157 @@
+//! Runtime checks for which host functions a contract can rely on.
+//!
+//! ## How availability is determined
+//!
+//! Every host function is a WebAssembly import, and imports are resolved when the host
+//! instantiates the contract: a host that lacks one refuses to load the module at all, so a
+//! running contract can never trap on a missing import. There is also no host call that lists
+//! the imports a host provides. What does vary between networks is whether the ledger feature a
+//! function addresses has been enabled, for example MPTs or vaults.
+//!
+//! [`is_available`] therefore treats the base interface (field access, hashing, NFT accessors,
+//! floats, tracing) as always available, and for a function tied to a ledger feature asks the
+//! host whether the amendment introducing that feature is enabled. If the host cannot answer,
+//! the function is reported as unavailable.
+
+use crate::host;
+
+/// The functions the host exposes to contracts, one variant per import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostFunction {
+    // Getters
+    GetLedgerSqn,
+    GetParentLedgerTime,
+    GetParentLedgerHash,
+    GetBaseFee,
+    AmendmentEnabled,
+    CacheLedgerObj,
+    GetTxField,
+    GetCurrentLedgerObjField,
+    GetLedgerObjField,
+    GetTxNestedField,
+    GetCurrentLedgerObjNestedField,
+    GetLedgerObjNestedField,
+    GetTxArrayLen,
+    GetCurrentLedgerObjArrayLen,
+    GetLedgerObjArrayLen,
+    GetTxNestedArrayLen,
+    GetCurrentLedgerObjNestedArrayLen,
+    GetLedgerObjNestedArrayLen,
+    // Update current ledger entry
+    UpdateData,
+    // Hash and keylet computation
+    ComputeSha512Half,
+    CheckSig,
+    AccountKeylet,
+    AmmKeylet,
+    CheckKeylet,
+    CredentialKeylet,
+    DelegateKeylet,
+    DepositPreauthKeylet,
+    DidKeylet,
+    EscrowKeylet,
+    LineKeylet,
+    MptIssuanceKeylet,
+    MptokenKeylet,
+    NftOfferKeylet,
+    OfferKeylet,
+    OracleKeylet,
+    PaychanKeylet,
+    PermissionedDomainKeylet,
+    SignersKeylet,
+    TicketKeylet,
+    VaultKeylet,
+    // NFT
+    GetNft,
+    GetNftIssuer,
+    GetNftTaxon,
+    GetNftFlags,
+    GetNftTransferFee,
+    GetNftSerial,
+    // Float
+    FloatFromInt,
+    FloatFromUint,
+    FloatSet,
+    FloatCompare,
+    FloatAdd,
+    FloatSubtract,
+    FloatMultiply,
+    FloatDivide,
+    FloatPow,
+    FloatRoot,
+    FloatLog,
+    // Trace
+    Trace,
+    TraceNum,
+    TraceAccount,
+    TraceOpaqueFloat,
+    TraceAmount,
+}
+
+impl HostFunction {
+    /// Returns the name of the amendment that introduced the ledger feature this function
+    /// addresses, or `None` if it belongs to the base interface.
+    pub const fn required_amendment(self) -> Option<&'static str> {
+        match self {
+            HostFunction::AmmKeylet => Some("AMM"),
+            HostFunction::CredentialKeylet => Some("Credentials"),
+            HostFunction::DelegateKeylet => Some("PermissionDelegation"),
+            HostFunction::DidKeylet => Some("DID"),
+            HostFunction::MptIssuanceKeylet | HostFunction::MptokenKeylet => Some("MPTokensV1"),
+            HostFunction::OracleKeylet => Some("PriceOracle"),
+            HostFunction::PermissionedDomainKeylet => Some("PermissionedDomains"),
+            HostFunction::VaultKeylet => Some("SingleAssetVault"),
+            _ => None,
+        }
+    }
+}
+
+/// Returns `true` if `func` can be relied on in the current ledger.
+///
+/// Functions in the base interface always return `true`. For a function tied to a ledger
+/// feature (see [`HostFunction::required_amendment`]) this asks the host whether that
+/// amendment is enabled, and returns `false` if it is not or if the host gives no clear
+/// answer. See the [module docs](self) for why imports themselves cannot be probed.
+pub fn is_available(func: HostFunction) -> bool {
+    match func.required_amendment() {
+        None => true,
+        Some(amendment) => {
+            let result_code =
+                unsafe { host::amendment_enabled(amendment.as_ptr(), amendment.len()) };
+            result_code == 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_interface_is_available() {
+        assert!(is_available(HostFunction::GetTxField));
+        assert!(is_available(HostFunction::EscrowKeylet));
+        assert!(is_available(HostFunction::GetNftTaxon));
+        assert!(is_available(HostFunction::FloatLog));
+    }
+
+    #[test]
+    fn test_unclear_amendment_answer_is_unavailable() {
+        // The test host answers with the name length rather than 0 or 1.
+        assert!(!is_available(HostFunction::MptokenKeylet));
+        assert!(!is_available(HostFunction::VaultKeylet));
+    }
+
+    #[test]
+    fn test_required_amendment() {
+        assert_eq!(HostFunction::GetBaseFee.required_amendment(), None);
+        assert_eq!(
+            HostFunction::MptIssuanceKeylet.required_amendment(),
+            Some("MPTokensV1")
+        );
+        assert_eq!(
+            HostFunction::PermissionedDomainKeylet.required_amendment(),
+            Some("PermissionedDomains")
+        );
+    }
+}
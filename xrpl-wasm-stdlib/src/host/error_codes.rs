@@ -42,6 +42,14 @@ pub const INDEX_OUT_OF_BOUNDS: i32 = -18;
 pub const INVALID_FLOAT_INPUT: i32 = -19;
 /// An error occurred during floating-point computation.
 pub const INVALID_FLOAT_COMPUTATION: i32 = -20;
+/// The requested capability is not exposed by the current host. Unlike the codes above, this
+/// code is produced by this library rather than returned by the host, so it is kept well away
+/// from the host's range.
+pub const NOT_IMPLEMENTED: i32 = -100;
+
+/// A loop bounded by a `StepBudget` ran out of steps before finishing. Like `NOT_IMPLEMENTED`,
+/// this code is produced by this library rather than returned by the host.
+pub const BUDGET_EXHAUSTED: i32 = -101;
 
 /// Evaluates a result code and executes a closure on success (result_code > 0).
 ///
@@ -396,6 +404,8 @@ mod tests {
             INDEX_OUT_OF_BOUNDS,
             INVALID_FLOAT_INPUT,
             INVALID_FLOAT_COMPUTATION,
+            NOT_IMPLEMENTED,
+            BUDGET_EXHAUSTED,
         ];
 
         for &code in &error_codes {
@@ -426,6 +436,8 @@ mod tests {
             INDEX_OUT_OF_BOUNDS,
             INVALID_FLOAT_INPUT,
             INVALID_FLOAT_COMPUTATION,
+            NOT_IMPLEMENTED,
+            BUDGET_EXHAUSTED,
         ];
 
         // Check that all error codes are unique by comparing each pair
@@ -465,6 +477,8 @@ mod tests {
             INDEX_OUT_OF_BOUNDS,
             INVALID_FLOAT_INPUT,
             INVALID_FLOAT_COMPUTATION,
+            NOT_IMPLEMENTED,
+            BUDGET_EXHAUSTED,
         ];
 
         for &code in &test_codes {
@@ -1,8 +1,9 @@
-use crate::host::Result;
+use crate::core::locator::Locator;
 use crate::host::error_codes::{
     match_result_code, match_result_code_optional, match_result_code_with_expected_bytes,
     match_result_code_with_expected_bytes_optional,
 };
+use crate::host::{Error, Result};
 
 /// Helper function for retrieving fixed-size fields with exact byte validation.
 ///
@@ -187,3 +188,92 @@ where
         Some((unsafe { buffer.assume_init() }, len))
     })
 }
+
+/// Serialized type codes, as returned by [`field_type`].
+///
+/// These are the `type_code` half of an sfield code (`type_code << 16 | field_code`).
+pub mod type_codes {
+    pub const UINT16: u16 = 1;
+    pub const UINT32: u16 = 2;
+    pub const UINT64: u16 = 3;
+    pub const HASH128: u16 = 4;
+    pub const HASH256: u16 = 5;
+    pub const AMOUNT: u16 = 6;
+    pub const BLOB: u16 = 7;
+    pub const ACCOUNT_ID: u16 = 8;
+    pub const NUMBER: u16 = 9;
+    pub const INT32: u16 = 10;
+    pub const INT64: u16 = 11;
+    pub const OBJECT: u16 = 14;
+    pub const ARRAY: u16 = 15;
+    pub const UINT8: u16 = 16;
+    pub const HASH160: u16 = 17;
+    pub const PATH_SET: u16 = 18;
+    pub const VECTOR256: u16 = 19;
+    pub const UINT96: u16 = 20;
+    pub const HASH192: u16 = 21;
+    pub const UINT384: u16 = 22;
+    pub const UINT512: u16 = 23;
+    pub const ISSUE: u16 = 24;
+    pub const XCHAIN_BRIDGE: u16 = 25;
+    pub const CURRENCY: u16 = 26;
+}
+
+/// Returns the serialized type code of the field `locator` points at, so a generic decoder can
+/// branch on it (see [`type_codes`]).
+///
+/// The type is derived from the last element of the locator without calling the host: an sfield
+/// code carries its type in the upper 16 bits, and an array index always selects an inner object
+/// ([`type_codes::OBJECT`]). Type codes without a constant in [`type_codes`] are returned as-is.
+///
+/// # Returns
+///
+/// * `Ok(u16)` - The serialized type code
+/// * `Err(Error::LocatorMalformed)` - If nothing has been packed into `locator`
+pub fn field_type(locator: &Locator) -> Result<u16> {
+    match locator.last() {
+        Some(last) if last >> 16 == 0 => Result::Ok(type_codes::OBJECT),
+        Some(last) => Result::Ok((last >> 16) as u16),
+        None => Result::Err(Error::LocatorMalformed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::host::error_codes::LOCATOR_MALFORMED;
+    use crate::sfield;
+
+    #[test]
+    fn test_field_type_of_leaf_fields() {
+        let mut locator = Locator::new();
+        locator.pack(sfield::Sequence);
+        assert_eq!(field_type(&locator).unwrap(), type_codes::UINT32);
+        locator.repack_last(sfield::Amount);
+        assert_eq!(field_type(&locator).unwrap(), type_codes::AMOUNT);
+        locator.repack_last(sfield::Account);
+        assert_eq!(field_type(&locator).unwrap(), type_codes::ACCOUNT_ID);
+        locator.repack_last(sfield::MemoData);
+        assert_eq!(field_type(&locator).unwrap(), type_codes::BLOB);
+    }
+
+    #[test]
+    fn test_field_type_of_array_and_element() {
+        let mut locator = Locator::new();
+        locator.pack(sfield::Memos);
+        assert_eq!(field_type(&locator).unwrap(), type_codes::ARRAY);
+        locator.pack(0);
+        assert_eq!(field_type(&locator).unwrap(), type_codes::OBJECT);
+    }
+
+    #[test]
+    fn test_field_type_of_unknown_type_is_raw() {
+        let mut locator = Locator::new();
+        locator.pack((10001 << 16) | 1);
+        assert_eq!(field_type(&locator).unwrap(), 10001);
+        assert_eq!(
+            field_type(&Locator::new()).err().unwrap().code(),
+            LOCATOR_MALFORMED
+        );
+    }
+}
@@ -11,6 +11,135 @@ pub const FLOAT_ROUNDING_MODES_DOWNWARD: i32 = 2;
 #[allow(unused)]
 pub const FLOAT_ROUNDING_MODES_UPWARD: i32 = 3;
 
+/// Scripted field values for unit tests, so a test can feed real bytes through the same host
+/// calls a contract makes. Fields that are not scripted keep the stub behavior of the functions
+/// below. State is kept per thread, so tests running in parallel do not see each other's values.
+#[cfg(test)]
+pub(crate) mod mock {
+    extern crate std;
+
+    use crate::core::locator::Locator;
+    use std::cell::{Cell, RefCell};
+    use std::vec::Vec;
+
+    /// A scripted field keyed by `K`: `Some(bytes)` is returned as the field's value, `None` as
+    /// absent.
+    type Field<K> = (K, Option<Vec<u8>>);
+
+    std::thread_local! {
+        static TX_FIELDS: RefCell<Vec<Field<i32>>> = const { RefCell::new(Vec::new()) };
+        static TX_NESTED_FIELDS: RefCell<Vec<Field<Vec<u8>>>> = const { RefCell::new(Vec::new()) };
+        static CURRENT_OBJ_FIELDS: RefCell<Vec<Field<i32>>> = const { RefCell::new(Vec::new()) };
+        static ACTIVE: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// Scripts the test host for as long as it is alive; dropping it restores the stubs.
+    pub(crate) struct MockHost {
+        _private: (),
+    }
+
+    impl MockHost {
+        pub(crate) fn new() -> Self {
+            reset();
+            ACTIVE.with(|active| active.set(true));
+            MockHost { _private: () }
+        }
+
+        /// Makes `get_tx_field(field_code)` return `value`.
+        pub(crate) fn set_tx_field(&self, field_code: i32, value: &[u8]) {
+            TX_FIELDS.with(|fields| set(&mut fields.borrow_mut(), field_code, Some(value)));
+        }
+
+        /// Makes `get_tx_field(field_code)` report `FIELD_NOT_FOUND`.
+        pub(crate) fn remove_tx_field(&self, field_code: i32) {
+            TX_FIELDS.with(|fields| set(&mut fields.borrow_mut(), field_code, None));
+        }
+
+        /// Makes `get_tx_nested_field` at `locator` return `value`.
+        pub(crate) fn set_tx_nested_field(&self, locator: &Locator, value: &[u8]) {
+            let key = unsafe { locator_bytes(locator.as_ptr(), locator.len()) };
+            TX_NESTED_FIELDS.with(|fields| set(&mut fields.borrow_mut(), key, Some(value)));
+        }
+
+        /// Makes `get_current_ledger_obj_field(field_code)` report `FIELD_NOT_FOUND`.
+        pub(crate) fn remove_current_ledger_obj_field(&self, field_code: i32) {
+            CURRENT_OBJ_FIELDS.with(|fields| set(&mut fields.borrow_mut(), field_code, None));
+        }
+    }
+
+    impl Drop for MockHost {
+        fn drop(&mut self) {
+            reset();
+        }
+    }
+
+    fn reset() {
+        TX_FIELDS.with(|fields| fields.borrow_mut().clear());
+        TX_NESTED_FIELDS.with(|fields| fields.borrow_mut().clear());
+        CURRENT_OBJ_FIELDS.with(|fields| fields.borrow_mut().clear());
+        ACTIVE.with(|active| active.set(false));
+    }
+
+    fn set<K: PartialEq>(fields: &mut Vec<Field<K>>, key: K, value: Option<&[u8]>) {
+        fields.retain(|(k, _)| *k != key);
+        fields.push((key, value.map(|bytes| bytes.to_vec())));
+    }
+
+    fn get<K: PartialEq>(fields: &[Field<K>], key: &K) -> Option<Option<Vec<u8>>> {
+        fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.clone())
+    }
+
+    unsafe fn locator_bytes(locator_ptr: *const u8, locator_len: usize) -> Vec<u8> {
+        unsafe { core::slice::from_raw_parts(locator_ptr, locator_len) }.to_vec()
+    }
+
+    /// Returns the scripted value of a transaction field, if it has one.
+    pub(super) fn tx_field(field_code: i32) -> Option<Option<Vec<u8>>> {
+        TX_FIELDS.with(|fields| get(&fields.borrow(), &field_code))
+    }
+
+    /// Returns the scripted value of a nested transaction field, if it has one.
+    pub(super) unsafe fn tx_nested_field(
+        locator_ptr: *const u8,
+        locator_len: usize,
+    ) -> Option<Option<Vec<u8>>> {
+        let key = unsafe { locator_bytes(locator_ptr, locator_len) };
+        TX_NESTED_FIELDS.with(|fields| get(&fields.borrow(), &key))
+    }
+
+    /// Returns the scripted value of a field of the current ledger object, if it has one.
+    pub(super) fn current_ledger_obj_field(field_code: i32) -> Option<Option<Vec<u8>>> {
+        CURRENT_OBJ_FIELDS.with(|fields| get(&fields.borrow(), &field_code))
+    }
+
+    /// Stores `data` as the current ledger object's `Data` field, as the host does for
+    /// `update_data`, so later reads in the same test see it. Does nothing without a live
+    /// [`MockHost`].
+    pub(super) fn update_data(data: &[u8]) {
+        if ACTIVE.with(|active| active.get()) {
+            CURRENT_OBJ_FIELDS.with(|fields| {
+                set(&mut fields.borrow_mut(), crate::sfield::Data, Some(data))
+            });
+        }
+    }
+
+    /// Copies a scripted value into the caller's buffer and returns the host result code.
+    pub(super) unsafe fn write_field(value: Option<Vec<u8>>, out_ptr: *mut u8, out_len: usize) -> i32 {
+        use crate::host::error_codes::{BUFFER_TOO_SMALL, FIELD_NOT_FOUND};
+        match value {
+            None => FIELD_NOT_FOUND,
+            Some(bytes) if bytes.len() > out_len => BUFFER_TOO_SMALL,
+            Some(bytes) => {
+                unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out_ptr, bytes.len()) };
+                bytes.len() as i32
+            }
+        }
+    }
+}
+
 #[allow(unused)]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe fn get_ledger_sqn() -> i32 {
@@ -50,9 +179,19 @@ pub unsafe fn cache_ledger_obj(_keylet_ptr: *const u8, _keylet_len: usize, _cach
 #[allow(unused)]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe fn get_tx_field(_field: i32, _out_buff_ptr: *mut u8, _out_buff_len: usize) -> i32 {
+    #[cfg(test)]
+    if let Some(value) = mock::tx_field(_field) {
+        return unsafe { mock::write_field(value, _out_buff_ptr, _out_buff_len) };
+    }
+    // Zero-fill so transaction fields read in tests decode deterministically.
+    unsafe { core::ptr::write_bytes(_out_buff_ptr, 0, _out_buff_len) };
     _out_buff_len as i32
 }
 
+/// Byte written to every unscripted field of the current ledger object. It decodes as a positive
+/// XRP amount, so amount fields still parse.
+const CURRENT_LEDGER_OBJ_FILL: u8 = 0x5A;
+
 #[allow(unused)]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe fn get_current_ledger_obj_field(
@@ -60,6 +199,13 @@ pub unsafe fn get_current_ledger_obj_field(
     _out_buff_ptr: *mut u8,
     _out_buff_len: usize,
 ) -> i32 {
+    #[cfg(test)]
+    if let Some(value) = mock::current_ledger_obj_field(_field) {
+        return unsafe { mock::write_field(value, _out_buff_ptr, _out_buff_len) };
+    }
+    // Fill with a pattern unlike the zero-filled transaction fields, so a test can tell which
+    // object a value was read from.
+    unsafe { core::ptr::write_bytes(_out_buff_ptr, CURRENT_LEDGER_OBJ_FILL, _out_buff_len) };
     _out_buff_len as i32
 }
 
@@ -82,6 +228,10 @@ pub unsafe fn get_tx_nested_field(
     _out_buff_ptr: *mut u8,
     _out_buff_len: usize,
 ) -> i32 {
+    #[cfg(test)]
+    if let Some(value) = unsafe { mock::tx_nested_field(_locator_ptr, _locator_len) } {
+        return unsafe { mock::write_field(value, _out_buff_ptr, _out_buff_len) };
+    }
     _out_buff_len as i32
 }
 
@@ -154,6 +304,8 @@ pub unsafe fn get_ledger_obj_nested_array_len(
 #[allow(unused)]
 #[allow(clippy::missing_safety_doc)]
 pub unsafe fn update_data(_data_ptr: *const u8, _data_len: usize) -> i32 {
+    #[cfg(test)]
+    mock::update_data(unsafe { core::slice::from_raw_parts(_data_ptr, _data_len) });
     _data_len as i32
 }
 
@@ -279,6 +431,20 @@ pub unsafe fn escrow_keylet(
     _out_buff_ptr: *mut u8,
     _out_buff_len: usize,
 ) -> i32 {
+    // Derive the real keylet, so code that builds on it can be checked against rippled's.
+    #[cfg(test)]
+    if _account_len == crate::core::types::account_id::ACCOUNT_ID_SIZE && _out_buff_len >= 32 {
+        use sha2::{Digest, Sha512};
+        let owner = unsafe { core::slice::from_raw_parts(_account_ptr, _account_len) };
+        let owner = crate::core::types::account_id::AccountID::from(
+            <[u8; 20]>::try_from(owner).unwrap(),
+        );
+        let preimage =
+            crate::core::types::keylets::escrow_keylet_preimage(&owner, _sequence as u32);
+        let digest = Sha512::digest(preimage);
+        unsafe { core::ptr::copy_nonoverlapping(digest.as_ptr(), _out_buff_ptr, 32) };
+        return 32;
+    }
     _out_buff_len as i32
 }
 
@@ -19,10 +19,19 @@
 //! See the host_bindings documentation for detailed function signatures.
 
 pub mod assert;
+pub mod availability;
 pub mod error_codes;
 pub mod field_helpers;
 pub mod trace;
 
+pub use availability::{HostFunction, is_available};
+
+use crate::core::current_tx::escrow_finish::get_current_escrow_finish;
+use crate::core::current_tx::traits::EscrowFinishFields;
+use crate::core::locator::Locator;
+use crate::core::types::account_id::{ACCOUNT_ID_SIZE, AccountID};
+use crate::core::types::crypto_condition::Fulfillment;
+
 //////////////////////////////////////
 // Host functions (defined by the host)
 //////////////////////////////////////
@@ -228,6 +237,15 @@ pub enum Error {
     /// An error occurred during floating-point computation.
     /// This may indicate overflow, underflow, or other arithmetic errors.
     InvalidFloatComputation = error_codes::INVALID_FLOAT_COMPUTATION,
+
+    /// The requested capability is not exposed by the current host.
+    /// This error is produced by this library (never by the host) for wrappers whose underlying
+    /// host function does not exist yet.
+    NotImplemented = error_codes::NOT_IMPLEMENTED,
+
+    /// A bounded loop stopped because it ran out of steps, so its results are incomplete.
+    /// This error is produced by this library (never by the host); see `core::budget`.
+    BudgetExhausted = error_codes::BUDGET_EXHAUSTED,
 }
 
 impl Error {
@@ -249,3 +267,352 @@ impl From<Error> for i64 {
         val as i64
     }
 }
+
+/// The entry condition a smart escrow's WASM is being evaluated for.
+///
+/// Marked `#[non_exhaustive]` so new modes can be added as the host grows; match with a
+/// wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExecutionMode {
+    /// Deciding whether an `EscrowFinish` transaction may release the escrow.
+    Finish,
+    /// Deciding whether an `EscrowCancel` transaction may return the escrow to its owner.
+    Cancel,
+}
+
+/// Returns the entry condition the current WASM invocation is evaluating.
+///
+/// The host currently only invokes a smart escrow's `finish` export, for `EscrowFinish`
+/// transactions; cancellation is governed solely by `CancelAfter` and never runs WASM. This
+/// therefore always returns `ExecutionMode::Finish`. Contracts that branch on the mode will pick
+/// up `ExecutionMode::Cancel` without changes once the host can report it.
+#[inline]
+pub fn execution_mode() -> Result<ExecutionMode> {
+    Result::Ok(ExecutionMode::Finish)
+}
+
+/// The outcome of a smart escrow's `finish` entrypoint.
+///
+/// ## Derived Traits
+///
+/// - `Copy`: Efficient for this fieldless enum, enabling implicit copying
+/// - `PartialEq, Eq`: Enable comparisons
+/// - `Debug, Clone`: Standard traits for development and consistency
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowDecision {
+    /// Let the `EscrowFinish` transaction release the escrow.
+    Release,
+    /// Leave the escrow in place; the `EscrowFinish` transaction fails.
+    Keep,
+}
+
+impl EscrowDecision {
+    /// Returns the value `finish` must return for this decision: `1` to release, `0` to keep.
+    #[inline]
+    pub const fn code(self) -> i32 {
+        match self {
+            EscrowDecision::Release => 1,
+            EscrowDecision::Keep => 0,
+        }
+    }
+}
+
+/// `true` releases the escrow and `false` keeps it.
+impl From<bool> for EscrowDecision {
+    fn from(release: bool) -> Self {
+        if release {
+            EscrowDecision::Release
+        } else {
+            EscrowDecision::Keep
+        }
+    }
+}
+
+/// Traces `decision` and `reason`, then returns the code `finish` must return for `decision`,
+/// so an entrypoint can end with `return finish_traced(EscrowDecision::Keep, "...")`.
+///
+/// The trace line is `"Escrow released: <reason>"` or `"Escrow kept: <reason>"`. A failure to
+/// trace does not change the decision. With the `no-trace` feature enabled nothing is traced,
+/// and the returned code is the same.
+#[inline]
+pub fn finish_traced(decision: EscrowDecision, reason: &str) -> i32 {
+    #[cfg(feature = "no-trace")]
+    {
+        let _ = reason;
+    }
+
+    #[cfg(not(feature = "no-trace"))]
+    {
+        let outcome = match decision {
+            EscrowDecision::Release => "released",
+            EscrowDecision::Keep => "kept",
+        };
+        let _ = trace::trace_fmt(format_args!("Escrow {outcome}: {reason}"));
+    }
+
+    decision.code()
+}
+
+/// Returns the amount a payment actually delivered (`DeliveredAmount`), read from the
+/// transaction's metadata.
+///
+/// For partial payments the delivered amount can be far smaller than the transaction's
+/// `Amount`, so this is the correct value for "how much was actually received."
+///
+/// Metadata only exists once a transaction has been applied, and a smart escrow runs while its
+/// triggering transaction is still being applied, so the host has no metadata to expose. This
+/// therefore always returns `Ok(None)`, the same as for a transaction without the field.
+/// Contracts must not treat `None` as "nothing was delivered"; without metadata, the only
+/// delivered value a contract can rely on is a ledger object's balance.
+#[inline]
+pub fn get_delivered_amount() -> Result<Option<crate::core::types::amount::Amount>> {
+    Result::Ok(None)
+}
+
+/// Submits `serialized_tx` as a transaction emitted by the running contract, returning the
+/// emitted transaction's hash.
+///
+/// The transaction would typically be assembled with
+/// [`ObjectBuilder`](crate::core::serialize::ObjectBuilder). Emission as it works for hooks
+/// carries constraints that would apply here too:
+///
+/// - **Reserves and fees**: the emitting account pays the emitted transaction's fee, and any
+///   objects it creates count against that account's owner reserve.
+/// - **Sequencing**: emitted transactions do not consume the account's `Sequence`; they use a
+///   ticket-like emission nonce and are applied in a later ledger, never the current one, so
+///   the contract cannot observe their effects.
+/// - **Limits**: the host caps how many transactions a single execution may emit.
+///
+/// Smart escrows cannot emit transactions yet, so this always returns
+/// `Error::NotImplemented` without inspecting `serialized_tx`.
+#[inline]
+pub fn emit(serialized_tx: &[u8]) -> Result<crate::core::types::uint::Hash256> {
+    let _ = serialized_tx;
+    Result::Err(Error::NotImplemented)
+}
+
+/// Reads the `UInt16` field at `locator` in the current transaction, such as an NFToken's
+/// `TransferFee`.
+///
+/// The host writes integer fields in little-endian order, as for every other integer getter in
+/// this crate. Returns `Error::InvalidField` if the field is not exactly 2 bytes wide, which
+/// catches a locator that points at a field of another type.
+#[inline]
+pub fn read_u16(locator: &Locator) -> Result<u16> {
+    match read_narrow::<2>(locator) {
+        Result::Ok(bytes) => Result::Ok(u16::from_le_bytes(bytes)),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Reads the `UInt8` field at `locator` in the current transaction, such as `TickSize`.
+///
+/// Returns `Error::InvalidField` if the field is not exactly 1 byte wide.
+#[inline]
+pub fn read_u8(locator: &Locator) -> Result<u8> {
+    match read_narrow::<1>(locator) {
+        Result::Ok([byte]) => Result::Ok(byte),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Reads the optional `AccountID` field at `locator` in the current transaction, such as a
+/// `Destination` or `RegularKey`.
+///
+/// # Returns
+///
+/// * `Ok(Some(AccountID))` - The field is present and exactly 20 bytes wide
+/// * `Ok(None)` - The transaction does not have the field
+/// * `Err(Error::InvalidField)` - The field is present but not 20 bytes wide
+/// * `Err(Error)` - Any other host failure, which is never reported as `None`
+#[inline]
+pub fn read_optional_account(locator: &Locator) -> Result<Option<AccountID>> {
+    let mut buffer = [0u8; ACCOUNT_ID_SIZE];
+    let result_code = unsafe {
+        get_tx_nested_field(
+            locator.as_ptr(),
+            locator.len(),
+            buffer.as_mut_ptr(),
+            buffer.len(),
+        )
+    };
+    optional_account_from_code(result_code, buffer)
+}
+
+/// Maps the result of reading an optional account field into `buffer`.
+fn optional_account_from_code(
+    result_code: i32,
+    buffer: [u8; ACCOUNT_ID_SIZE],
+) -> Result<Option<AccountID>> {
+    match result_code {
+        error_codes::FIELD_NOT_FOUND => Result::Ok(None),
+        code => match check_narrow_len::<ACCOUNT_ID_SIZE>(code) {
+            Result::Ok(()) => Result::Ok(Some(AccountID(buffer))),
+            Result::Err(e) => Result::Err(e),
+        },
+    }
+}
+
+/// Reads an `N`-byte field at `locator` from the current transaction.
+fn read_narrow<const N: usize>(locator: &Locator) -> Result<[u8; N]> {
+    let mut buffer = [0u8; N];
+    let result_code = unsafe {
+        get_tx_nested_field(
+            locator.as_ptr(),
+            locator.len(),
+            buffer.as_mut_ptr(),
+            buffer.len(),
+        )
+    };
+    match check_narrow_len::<N>(result_code) {
+        Result::Ok(()) => Result::Ok(buffer),
+        Result::Err(e) => Result::Err(e),
+    }
+}
+
+/// Maps a host result code for an `N`-byte read, rejecting any other length.
+fn check_narrow_len<const N: usize>(result_code: i32) -> Result<()> {
+    match result_code {
+        code if code as usize == N => Result::Ok(()),
+        code if code >= 0 => Result::Err(Error::InvalidField),
+        code => Result::Err(Error::from_code(code)),
+    }
+}
+
+/// Returns the crypto-condition fulfillment supplied by the `EscrowFinish` transaction that is
+/// finishing the escrow.
+///
+/// This is the transaction's `Fulfillment` field, the counterpart of the escrow object's
+/// `Condition`. Comparing the two lets a contract apply its own checks on top of rippled's,
+/// which has already verified that the fulfillment satisfies the condition by the time the
+/// contract runs.
+///
+/// Shorthand for [`EscrowFinishFields::get_fulfillment`] on [`get_current_escrow_finish`].
+///
+/// # Returns
+///
+/// * `Ok(Some(Fulfillment))` - The fulfillment bytes
+/// * `Ok(None)` - No fulfillment was supplied, as for a purely time-based escrow
+/// * `Err(Error)` - If the host fails to read the field
+#[inline]
+pub fn get_fulfillment() -> Result<Option<Fulfillment>> {
+    get_current_escrow_finish().get_fulfillment()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finish_traced_returns_decision_code() {
+        assert_eq!(finish_traced(EscrowDecision::Release, "NFT is owned"), 1);
+        assert_eq!(finish_traced(EscrowDecision::Keep, "NFT is not owned"), 0);
+        assert_eq!(EscrowDecision::from(true), EscrowDecision::Release);
+        assert_eq!(EscrowDecision::from(false).code(), 0);
+    }
+
+    #[test]
+    fn test_execution_mode_is_finish() {
+        assert_eq!(execution_mode().unwrap(), ExecutionMode::Finish);
+    }
+
+    #[test]
+    fn test_get_delivered_amount_without_metadata() {
+        assert!(get_delivered_amount().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_read_narrow_fields() {
+        let mut locator = Locator::new();
+        locator.pack(crate::sfield::TransferFee);
+        assert!(read_u16(&locator).is_ok());
+        locator.repack_last(crate::sfield::TickSize);
+        assert!(read_u8(&locator).is_ok());
+    }
+
+    #[test]
+    fn test_read_u16_is_little_endian() {
+        let host = mock::MockHost::new();
+        let mut locator = Locator::new();
+        locator.pack(crate::sfield::TransferFee);
+        host.set_tx_nested_field(&locator, &[0x34, 0x12]);
+        assert_eq!(read_u16(&locator).unwrap(), 0x1234);
+    }
+
+    #[test]
+    fn test_check_narrow_len_rejects_wrong_lengths() {
+        assert!(check_narrow_len::<2>(2).is_ok());
+        assert_eq!(
+            check_narrow_len::<2>(1).err().unwrap().code(),
+            error_codes::INVALID_FIELD
+        );
+        assert_eq!(
+            check_narrow_len::<2>(4).err().unwrap().code(),
+            error_codes::INVALID_FIELD
+        );
+        assert_eq!(
+            check_narrow_len::<1>(0).err().unwrap().code(),
+            error_codes::INVALID_FIELD
+        );
+        assert_eq!(
+            check_narrow_len::<1>(error_codes::FIELD_NOT_FOUND)
+                .err()
+                .unwrap()
+                .code(),
+            error_codes::FIELD_NOT_FOUND
+        );
+    }
+
+    #[test]
+    fn test_read_optional_account_present() {
+        // The test host reports every field as present and filling the buffer.
+        let mut locator = Locator::new();
+        locator.pack(crate::sfield::Destination);
+        assert!(read_optional_account(&locator).unwrap().is_some());
+        locator.repack_last(crate::sfield::RegularKey);
+        assert!(read_optional_account(&locator).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_read_optional_account_absent_and_errors() {
+        let buffer = [7u8; 20];
+        assert!(
+            optional_account_from_code(error_codes::FIELD_NOT_FOUND, buffer)
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            optional_account_from_code(20, buffer).unwrap().unwrap().0,
+            buffer
+        );
+        assert_eq!(
+            optional_account_from_code(8, buffer).err().unwrap().code(),
+            error_codes::INVALID_FIELD
+        );
+        assert_eq!(
+            optional_account_from_code(error_codes::LOCATOR_MALFORMED, buffer)
+                .err()
+                .unwrap()
+                .code(),
+            error_codes::LOCATOR_MALFORMED
+        );
+    }
+
+    #[test]
+    fn test_get_fulfillment_reads_tx_field() {
+        use crate::core::types::crypto_condition::FULFILLMENT_MAX_SIZE;
+
+        // The test host reports every field as present and filling the buffer.
+        let fulfillment = get_fulfillment().unwrap().unwrap();
+        assert_eq!(fulfillment.len, FULFILLMENT_MAX_SIZE);
+    }
+
+    #[test]
+    fn test_emit_is_unsupported() {
+        assert_eq!(
+            emit(&[0x12, 0x00, 0x00]).err().unwrap().code(),
+            error_codes::NOT_IMPLEMENTED
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use crate::host::error_codes::match_result_code;
 
+use crate::core::locator::Locator;
 use crate::core::types::account_id::AccountID;
 use crate::core::types::amount::Amount;
 use crate::host;
@@ -42,10 +43,15 @@ pub fn trace(msg: &str) -> Result<i32> {
     match_result_code(result_code, || result_code)
 }
 
+/// Text traced in place of empty data, so an empty blob is distinguishable from a missing line.
+pub const EMPTY_DATA_TEXT: &str = "<empty>";
+
 /// Write the contents of a message to the xrpld trace log.
 ///
 /// # Parameters
 /// * `msg`: A str ref pointing to an array of bytes containing UTF-8 characters.
+/// * `data`: The data to trace after the message. Empty data is traced as
+///   [`EMPTY_DATA_TEXT`] regardless of `data_repr`.
 ///
 /// # Returns
 ///
@@ -54,6 +60,36 @@ pub fn trace(msg: &str) -> Result<i32> {
 /// an error (e.g., incorrect buffer sizes).
 #[inline(always)] // <-- Inline because this function is very small
 pub fn trace_data(msg: &str, data: &[u8], data_repr: DataRepr) -> Result<i32> {
+    trace_bytes_msg(msg.as_bytes(), data, data_repr)
+}
+
+/// Write a message given as raw bytes, and data, to the xrpld trace log.
+///
+/// This is [`trace_data`] for messages assembled at runtime that may not be valid UTF-8.
+///
+/// # Message encoding
+///
+/// The host receives the message as a pointer and a length, so it reads exactly `msg.len()`
+/// bytes: a NUL byte does not end the message early. The bytes are copied into the log line
+/// unchanged and are expected to be UTF-8; the host does not validate them, so how another
+/// encoding is displayed is up to the log viewer. Use `data_repr = DataRepr::AsHex` with the
+/// bytes as `data` when they must be recoverable exactly.
+///
+/// # Parameters
+/// * `msg`: The message bytes, ideally UTF-8.
+/// * `data`: The data to trace after the message. Empty data is traced as
+///   [`EMPTY_DATA_TEXT`] regardless of `data_repr`.
+///
+/// # Returns
+///
+/// Returns an integer representing the result of the operation. A value of `0` or higher signifies
+/// the number of message bytes that were written to the trace function. Non-zero values indicate
+/// an error (e.g., incorrect buffer sizes).
+#[inline(always)] // <-- Inline because this function is very small
+pub fn trace_bytes_msg(msg: &[u8], data: &[u8], data_repr: DataRepr) -> Result<i32> {
+    if data.is_empty() {
+        return trace_bytes_msg(msg, EMPTY_DATA_TEXT.as_bytes(), DataRepr::AsUTF8);
+    }
     let result_code = unsafe {
         let data_ptr = data.as_ptr();
         let data_len = data.len();
@@ -117,6 +153,24 @@ pub fn trace_amount(msg: &str, amount: &Amount) -> Result<i32> {
     match_result_code(result_code, || result_code)
 }
 
+/// Write an amount to the xrpld trace log as exact decimal text.
+///
+/// Unlike [`trace_amount`], which hands the raw bytes to the host, this renders the value in the
+/// contract using integer arithmetic only, so issued amounts are traced digit for digit with no
+/// floating-point rounding. The line is `msg` followed by the amount's `Display` form:
+///
+/// - XRP: `"<msg><value>"`, with the value in XRP (not drops)
+/// - IOU: `"<msg><value>/<currency>/<issuer>"`, where `<value>` is the exact decimal value,
+///   `<currency>` is the 3-character code or 40 hex characters for non-standard codes, and
+///   `<issuer>` is the issuer's AccountID in hex
+/// - MPT: `"<msg><units> MPT"`
+///
+/// Like [`trace!`](crate::trace), the text is formatted into a stack buffer and long messages
+/// are truncated.
+pub fn trace_amount_exact(msg: &str, amount: &Amount) -> Result<i32> {
+    trace_fmt(format_args!("{msg}{amount}"))
+}
+
 /// Write a float to the XRPLD trace log
 #[inline(always)]
 pub fn trace_float(msg: &str, f: &[u8; 8]) -> Result<i32> {
@@ -124,11 +178,297 @@ pub fn trace_float(msg: &str, f: &[u8; 8]) -> Result<i32> {
     match_result_code(result_code, || result_code)
 }
 
+/// Number of data bytes rendered on each `trace_hexdump` line.
+const HEXDUMP_BYTES_PER_LINE: usize = 16;
+
+/// Maximum number of data bytes `trace_hexdump` renders before truncating the dump.
+pub const HEXDUMP_MAX_BYTES: usize = 512;
+
+/// Largest field value, in bytes, that [`trace_field`] reads.
+pub const TRACE_FIELD_MAX_BYTES: usize = 1024;
+
+/// Read the field at `locator` in the current transaction and write its value to the xrpld
+/// trace log, for quick debugging of a field that is not what it should be.
+///
+/// The value is traced like [`trace_data`] with `data_repr`. If the read fails (the field is
+/// absent, larger than [`TRACE_FIELD_MAX_BYTES`], or the locator is malformed), the line is
+/// `"<msg><read error <code>>"` instead and the error is not returned: the result only reflects
+/// the trace call itself.
+///
+/// With the `no-trace` feature enabled this function does nothing, skips the host read entirely
+/// and returns `Ok(0)`.
+pub fn trace_field(msg: &str, locator: &Locator, data_repr: DataRepr) -> Result<i32> {
+    #[cfg(feature = "no-trace")]
+    {
+        let _ = (msg, locator, data_repr);
+        Result::Ok(0)
+    }
+
+    #[cfg(not(feature = "no-trace"))]
+    {
+        let mut buffer = [0u8; TRACE_FIELD_MAX_BYTES];
+        let result_code = unsafe {
+            host::get_tx_nested_field(
+                locator.as_ptr(),
+                locator.len(),
+                buffer.as_mut_ptr(),
+                buffer.len(),
+            )
+        };
+        match result_code {
+            len if len >= 0 => trace_data(msg, &buffer[..len as usize], data_repr),
+            code => trace_fmt(format_args!("{msg}<read error {code}>")),
+        }
+    }
+}
+
+/// Length of one rendered hexdump line: an 8-digit offset, two spaces, 16 hex pairs each followed
+/// by a space, an extra space between the two 8-byte halves, then a space and the ASCII column
+/// in `|...|`.
+const HEXDUMP_LINE_SIZE: usize =
+    8 + 2 + HEXDUMP_BYTES_PER_LINE * 3 + 1 + 2 + HEXDUMP_BYTES_PER_LINE + 1;
+
+/// Write a canonical hexdump of `data` to the xrpld trace log, one line per 16 bytes.
+///
+/// The first line is `msg`, followed by lines of the form:
+///
+/// ```text
+/// 00000000  12 00 00 22 00 00 00 00  24 00 00 00 01 61 40 00  |..."....$....a@.|
+/// ```
+///
+/// Each line is assembled in a fixed stack buffer, so no allocation is needed. At most
+/// [`HEXDUMP_MAX_BYTES`] bytes of `data` are rendered; when `data` is longer, a final line reports
+/// how many bytes were omitted. Empty `data` is rendered as a single [`EMPTY_DATA_TEXT`] line.
+///
+/// With the `no-trace` feature enabled this function does nothing and returns `Ok(0)`.
+///
+/// # Returns
+///
+/// Returns the sum of the values returned by the individual trace calls, or the first error.
+pub fn trace_hexdump(msg: &str, data: &[u8]) -> Result<i32> {
+    #[cfg(feature = "no-trace")]
+    {
+        let _ = (msg, data);
+        Result::Ok(0)
+    }
+
+    #[cfg(not(feature = "no-trace"))]
+    {
+        let mut total = match trace(msg) {
+            Result::Ok(n) => n,
+            Result::Err(e) => return Result::Err(e),
+        };
+
+        if data.is_empty() {
+            return match trace(EMPTY_DATA_TEXT) {
+                Result::Ok(n) => Result::Ok(total + n),
+                Result::Err(e) => Result::Err(e),
+            };
+        }
+
+        let shown = data.len().min(HEXDUMP_MAX_BYTES);
+        let mut line = [0u8; HEXDUMP_LINE_SIZE];
+        for (i, chunk) in data[..shown].chunks(HEXDUMP_BYTES_PER_LINE).enumerate() {
+            let len = format_hexdump_line(i * HEXDUMP_BYTES_PER_LINE, chunk, &mut line);
+            // Only ASCII is ever written into `line`, so this cannot fail.
+            let text = unsafe { core::str::from_utf8_unchecked(&line[..len]) };
+            match trace(text) {
+                Result::Ok(n) => total += n,
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+
+        if data.len() > shown {
+            match trace_num("... bytes omitted:", (data.len() - shown) as i64) {
+                Result::Ok(n) => total += n,
+                Result::Err(e) => return Result::Err(e),
+            }
+        }
+
+        Result::Ok(total)
+    }
+}
+
+/// Renders one hexdump line for `chunk` (at most 16 bytes) starting at `offset` into `out`,
+/// returning the number of bytes written.
+#[cfg_attr(feature = "no-trace", allow(dead_code))]
+fn format_hexdump_line(offset: usize, chunk: &[u8], out: &mut [u8; HEXDUMP_LINE_SIZE]) -> usize {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    let mut pos = 0;
+    for shift in (0..8).rev() {
+        out[pos] = HEX[(offset >> (shift * 4)) & 0xF];
+        pos += 1;
+    }
+    out[pos] = b' ';
+    out[pos + 1] = b' ';
+    pos += 2;
+
+    for i in 0..HEXDUMP_BYTES_PER_LINE {
+        match chunk.get(i) {
+            Some(byte) => {
+                out[pos] = HEX[(byte >> 4) as usize];
+                out[pos + 1] = HEX[(byte & 0xF) as usize];
+            }
+            None => {
+                out[pos] = b' ';
+                out[pos + 1] = b' ';
+            }
+        }
+        out[pos + 2] = b' ';
+        pos += 3;
+        if i == 7 {
+            out[pos] = b' ';
+            pos += 1;
+        }
+    }
+
+    out[pos] = b' ';
+    out[pos + 1] = b'|';
+    pos += 2;
+    for byte in chunk {
+        out[pos] = if byte.is_ascii_graphic() || *byte == b' ' {
+            *byte
+        } else {
+            b'.'
+        };
+        pos += 1;
+    }
+    out[pos] = b'|';
+    pos + 1
+}
+
+/// Size of the stack buffer the [`trace!`](crate::trace) macro formats each message into.
+/// Longer messages are truncated and end in `...`.
+pub const TRACE_FORMAT_BUFFER_SIZE: usize = 256;
+
+/// Marker appended to messages that did not fit in [`TRACE_FORMAT_BUFFER_SIZE`] bytes.
+const TRUNCATION_MARKER: &str = "...";
+
+/// A fixed-size `core::fmt::Write` sink that silently truncates at a UTF-8 character boundary
+/// once it is full.
+#[cfg_attr(feature = "no-trace", allow(dead_code))]
+struct TraceFormatBuffer {
+    buf: [u8; TRACE_FORMAT_BUFFER_SIZE],
+    len: usize,
+    truncated: bool,
+}
+
+#[cfg_attr(feature = "no-trace", allow(dead_code))]
+impl TraceFormatBuffer {
+    const fn new() -> Self {
+        Self {
+            buf: [0u8; TRACE_FORMAT_BUFFER_SIZE],
+            len: 0,
+            truncated: false,
+        }
+    }
+
+    /// Returns the formatted message, ending in `...` if anything was cut off.
+    fn as_str(&mut self) -> &str {
+        if self.truncated {
+            let mut end = self
+                .len
+                .min(TRACE_FORMAT_BUFFER_SIZE - TRUNCATION_MARKER.len());
+            while end > 0 && (self.buf[end] & 0xC0) == 0x80 {
+                end -= 1;
+            }
+            self.buf[end..end + TRUNCATION_MARKER.len()]
+                .copy_from_slice(TRUNCATION_MARKER.as_bytes());
+            self.len = end + TRUNCATION_MARKER.len();
+            self.truncated = false;
+        }
+        // Only whole UTF-8 characters are ever copied in, so this cannot fail.
+        unsafe { core::str::from_utf8_unchecked(&self.buf[..self.len]) }
+    }
+}
+
+impl core::fmt::Write for TraceFormatBuffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        if self.truncated {
+            return Ok(());
+        }
+        let available = TRACE_FORMAT_BUFFER_SIZE - self.len;
+        let mut take = s.len();
+        if take > available {
+            take = available;
+            while !s.is_char_boundary(take) {
+                take -= 1;
+            }
+            self.truncated = true;
+        }
+        self.buf[self.len..self.len + take].copy_from_slice(&s.as_bytes()[..take]);
+        self.len += take;
+        // Never report an error: a truncated trace is more useful than none.
+        Ok(())
+    }
+}
+
+/// Formats `args` into a stack buffer and writes the result to the xrpld trace log as one line.
+///
+/// This is the implementation behind the [`trace!`](crate::trace) macro; prefer the macro.
+#[doc(hidden)]
+#[cfg_attr(feature = "no-trace", allow(dead_code))]
+pub fn trace_fmt(args: core::fmt::Arguments) -> Result<i32> {
+    let mut buffer = TraceFormatBuffer::new();
+    let _ = core::fmt::write(&mut buffer, args);
+    trace(buffer.as_str())
+}
+
+/// Formats a message and writes it to the xrpld trace log as a single line.
+///
+/// Accepts the same syntax as `format!`, but formats into a stack buffer of
+/// [`TRACE_FORMAT_BUFFER_SIZE`](crate::host::trace::TRACE_FORMAT_BUFFER_SIZE) bytes instead of
+/// allocating. Messages that do not fit are truncated and end in `...`. Evaluates to the
+/// `Result<i32>` returned by [`trace`](crate::host::trace::trace).
+///
+/// With the `no-trace` feature enabled, the macro does no formatting and evaluates to `Ok(0)`.
+/// The arguments are still type-checked but never evaluated.
+///
+/// # Examples
+///
+/// ```
+/// use xrpl_wasm_stdlib::trace;
+/// let owned = true;
+/// let count = 3;
+/// let _ = trace!("owned={} count={}", owned, count);
+/// ```
+#[cfg(not(feature = "no-trace"))]
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::host::trace::trace_fmt(format_args!($($arg)+))
+    };
+}
+
+/// Formats a message and writes it to the xrpld trace log as a single line.
+///
+/// The `no-trace` feature is enabled, so this does nothing and evaluates to `Ok(0)`.
+#[cfg(feature = "no-trace")]
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {{
+        if false {
+            let _ = format_args!($($arg)+);
+        }
+        $crate::host::Result::<i32>::Ok(0)
+    }};
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::core::types::amount::Amount;
 
+    #[test]
+    fn test_trace_bytes_msg_with_non_ascii_message() {
+        // Latin-1 "café", an embedded NUL and a lone UTF-8 continuation byte.
+        let msg = [b'c', b'a', b'f', 0xE9, 0x00, 0x80];
+        assert!(trace_bytes_msg(&msg, &[0xDE, 0xAD], DataRepr::AsHex).is_ok());
+        assert!(trace_bytes_msg(&msg, &[], DataRepr::AsHex).is_ok());
+        assert!(trace_bytes_msg("caf\u{e9}".as_bytes(), b"ok", DataRepr::AsUTF8).is_ok());
+    }
+
     #[test]
     fn test_trace_amount_xrp() {
         // Create a test XRP Amount
@@ -272,4 +612,102 @@ mod tests {
         assert_eq!(bytes[0], 0b_0110_0000); // Positive MPT prefix
         assert_eq!(&bytes[1..9], &VALUE.to_be_bytes()); // Amount bytes
     }
+
+    #[test]
+    fn test_format_hexdump_line_full() {
+        let data = *b"\x12\x00\x00\"ABCDEFGHIJK\xff";
+        let mut line = [0u8; HEXDUMP_LINE_SIZE];
+        let len = format_hexdump_line(0x10, &data, &mut line);
+
+        assert_eq!(len, HEXDUMP_LINE_SIZE);
+        assert_eq!(
+            &line[..len],
+            b"00000010  12 00 00 22 41 42 43 44  45 46 47 48 49 4a 4b ff  |...\"ABCDEFGHIJK.|"
+        );
+    }
+
+    #[test]
+    fn test_format_hexdump_line_partial() {
+        let mut line = [0u8; HEXDUMP_LINE_SIZE];
+        let len = format_hexdump_line(0, b"hi", &mut line);
+
+        let expected: &[u8] = b"00000000  68 69                                             |hi|";
+        assert_eq!(&line[..len], expected);
+    }
+
+    #[test]
+    fn test_trace_hexdump_caps_output() {
+        let data = [0xAAu8; HEXDUMP_MAX_BYTES + 100];
+        assert!(trace_hexdump("dump", &data).is_ok());
+        assert!(trace_hexdump("empty", &[]).is_ok());
+    }
+
+    fn format_into_buffer(args: core::fmt::Arguments) -> TraceFormatBuffer {
+        let mut buffer = TraceFormatBuffer::new();
+        let _ = core::fmt::write(&mut buffer, args);
+        buffer
+    }
+
+    #[test]
+    fn test_trace_macro_formats_values() {
+        let mut buffer = format_into_buffer(format_args!("owned={} count={}", true, 3));
+        assert_eq!(buffer.as_str(), "owned=true count=3");
+        assert!(crate::trace!("owned={} count={}", true, 3).is_ok());
+    }
+
+    #[test]
+    fn test_trace_macro_truncates_long_messages() {
+        let long = [b'a'; TRACE_FORMAT_BUFFER_SIZE + 10];
+        let long = core::str::from_utf8(&long).unwrap();
+        let mut buffer = format_into_buffer(format_args!("{long}"));
+        let text = buffer.as_str();
+        assert_eq!(text.len(), TRACE_FORMAT_BUFFER_SIZE);
+        assert!(text.ends_with("..."));
+    }
+
+    #[test]
+    fn test_trace_macro_truncates_on_char_boundary() {
+        // Fill the buffer so that a multi-byte character straddles the marker position.
+        let mut prefix = [b'a'; TRACE_FORMAT_BUFFER_SIZE - 4];
+        prefix[0] = b'b';
+        let prefix = core::str::from_utf8(&prefix).unwrap();
+        let mut buffer = format_into_buffer(format_args!("{prefix}\u{20AC}\u{20AC}"));
+        let text = buffer.as_str();
+        assert!(text.ends_with("a..."));
+        assert!(text.len() <= TRACE_FORMAT_BUFFER_SIZE);
+    }
+
+    #[test]
+    fn test_trace_amount_exact() {
+        use crate::core::types::account_id::AccountID;
+        use crate::core::types::currency::Currency;
+        use crate::core::types::opaque_float::{FLOAT_ONE, OpaqueFloat};
+
+        let iou = Amount::IOU {
+            amount: OpaqueFloat(FLOAT_ONE),
+            issuer: AccountID::from([3u8; 20]),
+            currency: Currency::from(*b"USD"),
+        };
+        assert!(trace_amount_exact("balance: ", &iou).is_ok());
+        assert!(trace_amount_exact("fee: ", &Amount::XRP { num_drops: 12 }).is_ok());
+    }
+
+    #[test]
+    fn test_trace_field() {
+        let mut locator = Locator::new();
+        locator.pack(crate::sfield::Memos);
+        locator.pack(0);
+        locator.pack(crate::sfield::MemoData);
+        assert!(trace_field("MemoData: ", &locator, DataRepr::AsHex).is_ok());
+        // An empty locator still traces rather than failing.
+        assert!(trace_field("nothing: ", &Locator::new(), DataRepr::AsUTF8).is_ok());
+    }
+
+    #[test]
+    fn test_trace_empty_data() {
+        use crate::core::types::blob::EMPTY_BLOB;
+
+        assert!(trace_data("Empty:", EMPTY_BLOB.as_slice(), DataRepr::AsHex).is_ok());
+        assert!(trace_hexdump("Empty:", EMPTY_BLOB.as_slice()).is_ok());
+    }
 }
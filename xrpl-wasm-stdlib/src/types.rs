@@ -1,3 +1,9 @@
 // TODO: Move these to the `types` crate.
 pub const XRPL_CONTRACT_DATA_SIZE: usize = 4096; //TODO size??
 pub type ContractData = [u8; XRPL_CONTRACT_DATA_SIZE];
+
+/// Short alias for [`NFToken`](crate::core::types::nft::NFToken).
+///
+/// This is the same type, not a separate representation, so raw `[u8; 32]` identifiers convert
+/// into it with `From`/`Into` and it can be passed anywhere an `NFToken` is expected.
+pub type NFT = crate::core::types::nft::NFToken;